@@ -0,0 +1,100 @@
+// @dev Pure hashing of the commitments, shared by the contract and the off-chain clients.
+// Only `core`, `alloc`, `hex` and `sha3` are used here so clients can build it without near-sdk,
+// accounts are taken as plain strings and nothing is read from the environment.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use sha3::{Digest, Keccak256};
+
+pub const MINER_COMMITMENT_DOMAIN: &str = "emip001/miner/v2";
+pub const VALIDATOR_COMMITMENT_DOMAIN: &str = "emip001/validator/v2";
+
+// @dev Borrowed form of an answer, the contract's `Answer` converts into it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnswerRef<'a> {
+    YesNo(bool),
+    Choice(u32),
+    Score(i64),
+    Content(&'a str),
+}
+
+pub fn hash_miner_commitment(request_id: &str, miner: &str, answer: AnswerRef, message: &str, salt: &str) -> String {
+    let mut encoded = Vec::new();
+
+    encode_str(&mut encoded, MINER_COMMITMENT_DOMAIN);
+    encode_str(&mut encoded, request_id);
+    encode_str(&mut encoded, miner);
+    encode_answer(&mut encoded, answer);
+    encode_str(&mut encoded, message);
+    encode_str(&mut encoded, salt);
+
+    keccak256_hex(&encoded)
+}
+
+pub fn hash_validator_commitment<A: AsRef<str>>(request_id: &str, validator: &str, answer: &[A], message: &str, salt: &str) -> String {
+    let mut encoded = Vec::new();
+
+    encode_str(&mut encoded, VALIDATOR_COMMITMENT_DOMAIN);
+    encode_str(&mut encoded, request_id);
+    encode_str(&mut encoded, validator);
+    encode_len(&mut encoded, answer.len());
+    answer.iter().for_each(|miner| encode_str(&mut encoded, miner.as_ref()));
+    encode_str(&mut encoded, message);
+    encode_str(&mut encoded, salt);
+
+    keccak256_hex(&encoded)
+}
+
+pub fn hash_legacy_miner_answer(request_id: &str, miner: &str, answer: bool, message: &str) -> String {
+    let concatenated_answer = alloc::format!("{}{}{}{}", request_id, miner, answer, message);
+
+    keccak256_hex(concatenated_answer.as_bytes())
+}
+
+pub fn hash_legacy_validator_answer<A: AsRef<str>>(request_id: &str, validator: &str, answer: &[A], message: &str) -> String {
+    let mut concatenated_answer: Vec<u8> = Vec::new();
+
+    concatenated_answer.extend_from_slice(request_id.as_bytes());
+    concatenated_answer.extend_from_slice(validator.as_bytes());
+    answer.iter().for_each(|miner| concatenated_answer.extend_from_slice(miner.as_ref().as_bytes()));
+    concatenated_answer.extend_from_slice(message.as_bytes());
+
+    keccak256_hex(&concatenated_answer)
+}
+
+// @dev Same bytes as the Borsh encoding, strings and sequences are prefixed with their length as a little endian u32
+fn encode_len(encoded: &mut Vec<u8>, len: usize) {
+    let len = u32::try_from(len).unwrap_or(u32::MAX);
+    encoded.extend_from_slice(&len.to_le_bytes());
+}
+
+fn encode_str(encoded: &mut Vec<u8>, value: &str) {
+    encode_len(encoded, value.len());
+    encoded.extend_from_slice(value.as_bytes());
+}
+
+// @dev Yes/no answers keep the single byte they had before the other kinds existed, every other kind starts with its own tag
+fn encode_answer(encoded: &mut Vec<u8>, answer: AnswerRef) {
+    match answer {
+        AnswerRef::YesNo(answer) => encoded.push(u8::from(answer)),
+        AnswerRef::Choice(option) => {
+            encoded.push(2);
+            encoded.extend_from_slice(&option.to_le_bytes());
+        }
+        AnswerRef::Score(score) => {
+            encoded.push(3);
+            encoded.extend_from_slice(&score.to_le_bytes());
+        }
+        AnswerRef::Content(content_hash) => {
+            encoded.push(4);
+            encode_str(encoded, content_hash);
+        }
+    }
+}
+
+fn keccak256_hex(data: &[u8]) -> String {
+    hex::encode(Keccak256::digest(data))
+}
@@ -0,0 +1,157 @@
+use near_sdk::{env, log, near_bindgen, require, AccountId};
+
+use crate::{
+    Contract, ContractExt, EventLog, EventLogVariant, OwnershipTransferredLog, PauseLog, PausePeriod, Role, RoleGrantedLog, RoleRevokedLog, UnpauseLog,
+};
+
+#[near_bindgen]
+impl Contract {
+    pub fn get_owner(&self) -> AccountId {
+        self.owner_id.clone()
+    }
+
+    // @dev Only the owner can hand over the contract, the previous owner keeps the roles it was granted
+    pub fn transfer_ownership(&mut self, new_owner: AccountId) {
+        self.assert_owner();
+        require!(new_owner != self.owner_id, "Account is already the owner");
+
+        let previous_owner = std::mem::replace(&mut self.owner_id, new_owner.clone());
+
+        let ownership_transferred_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::OwnershipTransferred(vec![OwnershipTransferredLog { previous_owner, new_owner }]),
+        };
+
+        log!(&ownership_transferred_log.to_string());
+    }
+
+    pub fn has_role(&self, role: Role, account_id: AccountId) -> bool {
+        account_id == self.owner_id || self.roles.contains(&(role, account_id))
+    }
+
+    // @dev The owner and the admins manage the roles, only the owner can grant or revoke the admin role
+    pub fn grant_role(&mut self, role: Role, account_id: AccountId) -> bool {
+        self.assert_can_manage_role(role);
+
+        if !self.roles.insert((role, account_id.clone())) {
+            log!("Role already granted: {}", account_id);
+            return false;
+        }
+
+        let role_granted_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::RoleGranted(vec![RoleGrantedLog {
+                role,
+                account: account_id,
+                sender: env::predecessor_account_id(),
+            }]),
+        };
+
+        log!(&role_granted_log.to_string());
+
+        true
+    }
+
+    pub fn revoke_role(&mut self, role: Role, account_id: AccountId) -> bool {
+        self.assert_can_manage_role(role);
+
+        if !self.roles.remove(&(role, account_id.clone())) {
+            log!("Role not granted: {}", account_id);
+            return false;
+        }
+
+        let role_revoked_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::RoleRevoked(vec![RoleRevokedLog {
+                role,
+                account: account_id,
+                sender: env::predecessor_account_id(),
+            }]),
+        };
+
+        log!(&role_revoked_log.to_string());
+
+        true
+    }
+
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    // @dev Emergency switch, registrations, requests, commits and reveals are blocked while paused.
+    // Unstaking, withdrawals, rewards and settlement keep working so nobody gets their funds locked.
+    pub fn pause(&mut self) {
+        self.assert_role(Role::Pauser);
+        require!(!self.paused, "Contract is already paused");
+
+        self.paused = true;
+        self.pause_periods.push(PausePeriod {
+            start: env::block_timestamp(),
+            end: None,
+        });
+
+        let pause_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::Pause(vec![PauseLog {
+                account: env::predecessor_account_id(),
+            }]),
+        };
+
+        log!(&pause_log.to_string());
+    }
+
+    pub fn unpause(&mut self) {
+        self.assert_role(Role::Pauser);
+        require!(self.paused, "Contract is not paused");
+
+        self.paused = false;
+        if let Some(pause) = self.pause_periods.len().checked_sub(1).and_then(|last| self.pause_periods.get_mut(last)) {
+            pause.end = Some(env::block_timestamp());
+        }
+
+        let unpause_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::Unpause(vec![UnpauseLog {
+                account: env::predecessor_account_id(),
+            }]),
+        };
+
+        log!(&unpause_log.to_string());
+    }
+}
+
+impl Contract {
+    pub(crate) fn assert_owner(&self) {
+        require!(env::predecessor_account_id() == self.owner_id, "Only the owner can call this method");
+    }
+
+    pub(crate) fn assert_role(&self, role: Role) {
+        require!(self.has_role(role, env::predecessor_account_id()), "Caller is missing the required role");
+    }
+
+    pub(crate) fn assert_not_paused(&self) {
+        require!(!self.paused, "Contract is paused");
+    }
+
+    // @dev Pauses are stored in the order they happened, so only the ones that ended after the window started are checked
+    pub(crate) fn was_paused_during(&self, start: u64, end: u64) -> bool {
+        self.pause_periods
+            .iter()
+            .rev()
+            .take_while(|pause| pause.end.is_none_or(|pause_end| pause_end > start))
+            .any(|pause| pause.start < end)
+    }
+
+    fn assert_can_manage_role(&self, role: Role) {
+        if role == Role::Admin {
+            self.assert_owner();
+        } else {
+            self.assert_role(Role::Admin);
+        }
+    }
+}
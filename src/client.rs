@@ -0,0 +1,85 @@
+use near_sdk::{env, ext_contract, log, near_bindgen, FunctionError, Gas, PromiseError};
+
+use crate::{
+    Contract, ContractError, ContractExt, DecisionDelivery, DecisionResult, DeliveryStatus, Hash, VersionedRequest, GAS_FOR_ON_GOVERNANCE_DECISION,
+    GAS_FOR_RESOLVE_DELIVERY, MAX_DELIVERY_ATTEMPTS,
+};
+
+// @dev Interface of the EarthMind client contract, every protocol implementing it receives the decision of its requests
+#[ext_contract(ext_earthmind_client)]
+pub trait EarthmindClient {
+    fn on_governance_decision(&mut self, request_id: Hash, result: DecisionResult);
+}
+
+#[near_bindgen]
+impl Contract {
+    // @dev Records whether the client accepted the decision. If the client panicked the decision is sent again,
+    // up to MAX_DELIVERY_ATTEMPTS times.
+    #[private]
+    pub fn on_decision_delivered(&mut self, request_id: Hash, #[callback_result] call_result: Result<(), PromiseError>) -> DeliveryStatus {
+        let Some(request) = self.requests.get_mut(&request_id).map(VersionedRequest::current_mut) else {
+            log!("Request is not registered: {}", request_id);
+            return DeliveryStatus::Failed;
+        };
+
+        let Some(delivery) = request.delivery.as_mut() else {
+            log!("Decision not sent: {}", request_id);
+            return DeliveryStatus::Failed;
+        };
+
+        if call_result.is_ok() {
+            delivery.status = DeliveryStatus::Delivered;
+            log!("Decision delivered: {}", request_id);
+            return DeliveryStatus::Delivered;
+        }
+
+        log!("Decision delivery failed: {}", request_id);
+
+        if delivery.attempts >= MAX_DELIVERY_ATTEMPTS {
+            delivery.status = DeliveryStatus::Failed;
+            return DeliveryStatus::Failed;
+        }
+
+        let result = request.result.clone().unwrap_or_else(|| panic!("Decision not found"));
+        self.deliver_decision(request_id, result);
+
+        DeliveryStatus::Pending
+    }
+
+    pub fn get_decision_delivery(&self, request_id: Hash) -> Option<DecisionDelivery> {
+        self.get_request(&request_id)?.delivery
+    }
+}
+
+impl Contract {
+    // @dev Sends the decision to the protocol that created the request and resolves the outcome in on_decision_delivered
+    pub(crate) fn deliver_decision(&mut self, request_id: Hash, result: DecisionResult) {
+        let request = self
+            .requests
+            .get_mut(&request_id)
+            .map(VersionedRequest::current_mut)
+            .unwrap_or_else(|| ContractError::RequestNotFound.panic());
+
+        let attempts = request.delivery.map_or(0, |delivery| delivery.attempts) + 1;
+        request.delivery = Some(DecisionDelivery {
+            status: DeliveryStatus::Pending,
+            attempts,
+        });
+
+        ext_earthmind_client::ext(request.sender.clone())
+            .with_static_gas(GAS_FOR_ON_GOVERNANCE_DECISION)
+            .on_governance_decision(request_id.clone(), result)
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(Self::resolve_delivery_gas(MAX_DELIVERY_ATTEMPTS - attempts))
+                    .on_decision_delivered(request_id),
+            );
+    }
+
+    // @dev The callback needs enough gas to send the decision again for every retry left
+    const fn resolve_delivery_gas(retries_left: u32) -> Gas {
+        let retry_gas = GAS_FOR_ON_GOVERNANCE_DECISION.as_gas() + GAS_FOR_RESOLVE_DELIVERY.as_gas();
+
+        Gas::from_gas(GAS_FOR_RESOLVE_DELIVERY.as_gas() + retries_left as u64 * retry_gas)
+    }
+}
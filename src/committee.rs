@@ -0,0 +1,101 @@
+use near_sdk::{env, near_bindgen, AccountId};
+use std::collections::HashSet;
+
+use crate::indexed_set::IndexedSet;
+use crate::{Committee, CommitteeConfig, Contract, ContractExt, Hash, Module, Participant, MAX_COMMITTEE_CANDIDATES};
+
+#[near_bindgen]
+impl Contract {
+    pub fn get_committee(&self, request_id: Hash) -> Option<Committee> {
+        self.get_request(&request_id).and_then(|request| request.committee.clone())
+    }
+
+    // @dev Requests without a committee accept every registered account, so every account counts as selected
+    pub fn is_selected(&self, request_id: Hash, participant: Participant, account_id: AccountId) -> bool {
+        self.get_request(&request_id)
+            .is_some_and(|request| request.committee.as_ref().is_none_or(|committee| committee.contains(participant, &account_id)))
+    }
+}
+
+impl Contract {
+    // @dev Samples the committee of a new request among the registered accounts that are not unbonding.
+    // The draws come from the random seed of the block, so they are known once the request is created.
+    // Only the miners that serve the module of the request are eligible.
+    // At most MAX_COMMITTEE_CANDIDATES positions of the index are read, each drawn on its own, so the cost doesn't grow
+    // with the number of registered accounts and accounts registered back to back are not read together.
+    // Positions drawn twice or left empty by a removed account are skipped.
+    pub(crate) fn select_committee(&self, request_id: &Hash, config: CommitteeConfig, module: Module) -> Committee {
+        let miners = self.miners_by_module.get(&module);
+
+        Committee {
+            miners: miners.map_or_else(Vec::new, |miners| self.sample_committee(request_id, Participant::Miner, config, miners)),
+            validators: self.sample_committee(request_id, Participant::Validator, config, &self.validator_index),
+        }
+    }
+
+    fn sample_committee(&self, request_id: &Hash, participant: Participant, config: CommitteeConfig, index: &IndexedSet<AccountId>) -> Vec<AccountId> {
+        let positions = index.positions();
+        let count = positions.end - positions.start;
+
+        let drawn: Vec<u32> = if count <= MAX_COMMITTEE_CANDIDATES {
+            positions.collect()
+        } else {
+            let mut drawn = HashSet::new();
+            (0..MAX_COMMITTEE_CANDIDATES as usize)
+                .map(|draw| positions.start + (Self::random_draw(request_id, participant, usize::MAX - draw) % u128::from(count)) as u32)
+                .filter(|position| drawn.insert(*position))
+                .collect()
+        };
+
+        // @dev Every account weighs 1 unless the committee is weighted by stake, in yoctoNEAR
+        let mut candidates: Vec<(AccountId, u128)> = drawn
+            .into_iter()
+            .filter_map(|position| index.get(position))
+            .filter(|account| !self.is_unbonding(participant, (*account).clone()))
+            .filter_map(|account| {
+                let stake = self.stakes(participant).get(account)?;
+                let weight = if config.stake_weighted { stake.as_yoctonear() } else { 1 };
+                Some((account.clone(), weight))
+            })
+            .collect();
+
+        let mut selected = Vec::new();
+        let mut total: u128 = candidates.iter().map(|(_, weight)| weight).sum();
+
+        while selected.len() < config.size(participant) as usize && total > 0 {
+            let mut draw = Self::random_draw(request_id, participant, selected.len()) % total;
+
+            let position = candidates
+                .iter()
+                .position(|(_, weight)| {
+                    if draw < *weight {
+                        return true;
+                    }
+                    draw -= weight;
+                    false
+                })
+                .unwrap_or_else(|| panic!("Draw out of range"));
+
+            let (account, weight) = candidates.swap_remove(position);
+            total -= weight;
+            selected.push(account);
+        }
+
+        selected
+    }
+
+    // @dev Every draw hashes the seed with the request and its position, so the committees of the requests created
+    // in the same block are different. Committee members are drawn from the first positions, candidates from the last ones.
+    fn random_draw(request_id: &Hash, participant: Participant, draw: usize) -> u128 {
+        let mut input = env::random_seed();
+        input.extend_from_slice(request_id.as_bytes());
+        input.push(participant as u8);
+        input.extend_from_slice(&(draw as u64).to_le_bytes());
+
+        let hash = env::sha256(&input);
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&hash[..16]);
+
+        u128::from_le_bytes(bytes)
+    }
+}
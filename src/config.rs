@@ -0,0 +1,41 @@
+use near_sdk::{env, log, near_bindgen, require, FunctionError};
+
+use crate::{Config, ConfigUpdatedLog, Contract, ContractError, ContractExt, EventLog, EventLogVariant, Role};
+
+#[near_bindgen]
+impl Contract {
+    pub const fn get_config(&self) -> Config {
+        self.config
+    }
+
+    // @dev The minimum stakes are only checked when an account registers. Miners and validators registered
+    // with a lower stake keep their registration and can keep committing, until they unstake.
+    // Fee managers can only change the minimum stakes, the registration fee and the request fee, admins can change everything.
+    pub fn update_config(&mut self, config: Config) {
+        if !self.has_role(Role::Admin, env::predecessor_account_id()) {
+            self.assert_role(Role::FeeManager);
+            require!(
+                self.config.with_amounts_of(&config) == config,
+                "Fee managers can only update the stakes and fees"
+            );
+        }
+
+        if !config.is_within_bounds() {
+            ContractError::ConfigOutOfBounds.panic();
+        }
+
+        let previous = std::mem::replace(&mut self.config, config);
+
+        let config_updated_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::ConfigUpdated(vec![ConfigUpdatedLog {
+                sender: env::predecessor_account_id(),
+                previous,
+                config,
+            }]),
+        };
+
+        log!(&config_updated_log.to_string());
+    }
+}
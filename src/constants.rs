@@ -1,10 +1,53 @@
-use near_sdk::NearToken;
+use near_sdk::{Gas, NearToken};
 
 pub const TWO_MINUTES: u64 = 2 * 60 * 1_000_000_000; // 2 minutes in nanoseconds
 pub const COMMIT_MINER_DURATION: u64 = TWO_MINUTES;
 pub const REVEAL_MINER_DURATION: u64 = TWO_MINUTES;
 pub const COMMIT_VALIDATOR_DURATION: u64 = TWO_MINUTES;
 pub const REVEAL_VALIDATOR_DURATION: u64 = TWO_MINUTES;
+pub const MIN_PHASE_DURATION: u64 = 1_000_000_000; // 1 second in nanoseconds
+pub const MAX_PHASE_DURATION: u64 = 7 * 24 * 60 * 60 * 1_000_000_000; // 7 days in nanoseconds
 pub const MIN_MINER_STAKE: NearToken = NearToken::from_near(1); // 1 NEAR
 pub const MIN_VALIDATOR_STAKE: NearToken = NearToken::from_near(10); // 10 NEAR
 pub const PROTOCOL_REGISTRATION_FEE: NearToken = NearToken::from_near(5);
+pub const MIN_CONFIG_AMOUNT: NearToken = NearToken::from_millinear(100); // 0.1 NEAR
+pub const MAX_CONFIG_AMOUNT: NearToken = NearToken::from_near(100_000);
+pub const MIN_REQUEST_FEE: NearToken = NearToken::from_near(1);
+pub const UNBONDING_PERIOD: u64 = 2 * 24 * 60 * 60 * 1_000_000_000; // 2 days in nanoseconds
+pub const MIN_UNBONDING_PERIOD: u64 = 60 * 60 * 1_000_000_000; // 1 hour in nanoseconds
+pub const MAX_UNBONDING_PERIOD: u64 = 30 * 24 * 60 * 60 * 1_000_000_000; // 30 days in nanoseconds
+pub const GAS_FOR_ON_GOVERNANCE_DECISION: Gas = Gas::from_tgas(10);
+pub const GAS_FOR_RESOLVE_DELIVERY: Gas = Gas::from_tgas(10);
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 3;
+pub const GAS_FOR_MIGRATE: Gas = Gas::from_tgas(100);
+pub const MAX_PAGE_LIMIT: u64 = 100;
+pub const MIN_SALT_LENGTH: usize = 32; // 16 random bytes encoded as hex
+pub const MAX_ANSWER_OPTIONS: u32 = 32;
+pub const CONTENT_HASH_LENGTH: usize = 64; // 32 bytes encoded as hex
+pub const DEFAULT_RANKING_SIZE: u32 = 10;
+pub const MIN_RANKING_SIZE: u32 = 1;
+pub const MAX_RANKING_SIZE: u32 = 50;
+pub const MAX_POSITION_WEIGHT: u32 = 1_000;
+pub const MIN_COMMITTEE_SIZE: u32 = 1;
+pub const MAX_COMMITTEE_SIZE: u32 = 100;
+pub const MAX_COMMITTEE_CANDIDATES: u32 = 200; // accounts read to sample a committee
+pub const MAX_CULTURE_TAGS: usize = 10;
+pub const MAX_CULTURE_TAG_LENGTH: usize = 32;
+pub const BASIS_POINTS: u128 = 10_000;
+pub const SLASH_BASIS_POINTS: u32 = 1_000; // 10% of the stake
+pub const MINER_REWARD_BASIS_POINTS: u32 = 7_000; // 70% of the reward pool, the rest goes to validators
+pub const DEREGISTRATION_REFUND_BASIS_POINTS: u32 = 5_000; // 50% of the registration fee
+
+// @dev Prefixes shared by every request before they were derived from the request id
+pub const LEGACY_MINER_PROPOSALS_PREFIX: &[u8] = b"miner_proposal";
+pub const LEGACY_VALIDATOR_PROPOSALS_PREFIX: &[u8] = b"validator_proposal";
+pub const LEGACY_VOTES_FOR_MINERS_PREFIX: &[u8] = b"votes_miners";
+
+// @dev Prefixes of the maps that may still hold entries written before their values were versioned
+pub const PROTOCOLS_PREFIX: &[u8] = b"protocols";
+pub const REQUESTS_PREFIX: &[u8] = b"requests";
+
+// @dev Prefixes of the maps added after the first upgrades, migrated state creates them empty
+pub const STAKE_DECREASES_PREFIX: &[u8] = b"stake_decreases";
+pub const MINER_MODULES_PREFIX: &[u8] = b"miner_modules";
+pub const MINERS_BY_MODULE_PREFIX: &[u8] = b"miners_by_module";
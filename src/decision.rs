@@ -0,0 +1,131 @@
+use near_sdk::{env, log, near_bindgen, FunctionError};
+use std::collections::BTreeMap;
+
+use crate::{
+    Answer, AnswerKind, Contract, ContractError, ContractExt, DecisionResult, EventLog, EventLogVariant, FinalizeRequestResult, Hash, Request,
+    RequestFinalizedLog, RequestState, VersionedRequest,
+};
+
+#[near_bindgen]
+impl Contract {
+    // @dev Permissionless step, once the request ended anyone can compute the answer to the governance question.
+    // If the top miners were not computed yet it is computed here, which also distributes the rewards.
+    // The decision is then sent to the protocol that created the request through the EarthMind client interface.
+    pub fn finalize_request(&mut self, request_id: Hash) -> FinalizeRequestResult {
+        let Some(request) = self.get_request(&request_id) else {
+            log!("Request is not registered: {}", request_id);
+            return FinalizeRequestResult::RequestNotFound;
+        };
+
+        if Self::get_stage(request) != RequestState::Ended {
+            log!("Request has not ended: {}", request_id);
+            return FinalizeRequestResult::NotEnded;
+        }
+
+        if request.result.is_some() {
+            log!("Request already finalized: {}", request_id);
+            return FinalizeRequestResult::AlreadyFinalized;
+        }
+
+        if !request.rewards_distributed {
+            self.get_top_voters(request_id.clone());
+        }
+
+        let request = self
+            .requests
+            .get_mut(&request_id)
+            .map(VersionedRequest::current_mut)
+            .unwrap_or_else(|| ContractError::RequestNotFound.panic());
+        let result = Self::compute_decision(request);
+        request.result = Some(result.clone());
+        let protocol = request.sender.clone();
+
+        // @dev A finalized request has nothing left to do, so it stops being listed as active
+        self.active_requests.remove(&request_id);
+        self.finish_request(&protocol);
+
+        let request_finalized_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::RequestFinalized(vec![RequestFinalizedLog {
+                request_id: request_id.clone(),
+                decision: result.decision.clone(),
+                answers: result.answers,
+                support: result.support,
+                revealed_miners: result.revealed_miners,
+                revealed_validators: result.revealed_validators,
+            }]),
+        };
+
+        env::log_str(&request_finalized_log.to_string());
+
+        self.deliver_decision(request_id, result);
+
+        FinalizeRequestResult::Success
+    }
+}
+
+impl Contract {
+    // @dev The decision is computed from the revealed answers of the top miners, according to the answer kind of the request
+    fn compute_decision(request: &Request) -> DecisionResult {
+        let answers: Vec<Answer> = request
+            .top_miners
+            .iter()
+            .filter_map(|(miner, _)| request.miners_proposals.get(miner).and_then(|proposal| proposal.answer.clone()))
+            .collect();
+
+        let decision = match request.answer_kind {
+            AnswerKind::YesNo => Self::majority(&answers),
+            AnswerKind::MultipleChoice { .. } | AnswerKind::Content => Self::plurality(&answers),
+            AnswerKind::Score { .. } => Self::median(&answers),
+        };
+        let support = decision
+            .as_ref()
+            .map_or(0, |decision| answers.iter().filter(|answer| *answer == decision).count() as u32);
+
+        DecisionResult {
+            decision,
+            answers: answers.len() as u32,
+            support,
+            revealed_miners: request.count_revealed_miners(),
+            committed_miners: request.committed_miners.len() as u32,
+            revealed_validators: request.count_revealed_validators(),
+            committed_validators: request.committed_validators.len() as u32,
+        }
+    }
+
+    // @dev Yes only if most of the answers are yes, a tie is no. Without answers there is no decision.
+    fn majority(answers: &[Answer]) -> Option<Answer> {
+        if answers.is_empty() {
+            return None;
+        }
+
+        let yes_votes = answers.iter().filter(|answer| **answer == Answer::YesNo(true)).count();
+
+        Some(Answer::YesNo(yes_votes * 2 > answers.len()))
+    }
+
+    // @dev Most repeated answer, a tie goes to the lowest answer
+    fn plurality(answers: &[Answer]) -> Option<Answer> {
+        let mut counts: BTreeMap<&Answer, u32> = BTreeMap::new();
+        for answer in answers {
+            *counts.entry(answer).or_default() += 1;
+        }
+
+        counts
+            .into_iter()
+            .fold(None, |best: Option<(&Answer, u32)>, (answer, count)| match best {
+                Some((_, best_count)) if best_count >= count => best,
+                _ => Some((answer, count)),
+            })
+            .map(|(answer, _)| answer.clone())
+    }
+
+    // @dev With an even number of answers the lower of the two middle answers is taken, so the decision is always one of the answers
+    fn median(answers: &[Answer]) -> Option<Answer> {
+        let mut sorted = answers.to_vec();
+        sorted.sort();
+
+        sorted.get(sorted.len().saturating_sub(1) / 2).cloned()
+    }
+}
@@ -0,0 +1,118 @@
+use near_sdk::store::Vector;
+use near_sdk::{near_bindgen, AccountId, FunctionError};
+
+use crate::indexed_set::IndexedSet;
+use crate::{Contract, ContractError, ContractExt, Hash, Module, RequestState, StorageKey, MAX_PAGE_LIMIT};
+
+#[near_bindgen]
+impl Contract {
+    // @dev Every list is paginated with from_index and limit, limit is capped at MAX_PAGE_LIMIT.
+    // Requests of a protocol are listed by creation. Accounts and active requests are listed by insertion,
+    // removing an entry doesn't change the order of the others.
+    pub fn get_miners(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<AccountId> {
+        Self::paginate(self.miner_index.iter().cloned(), from_index, limit)
+    }
+
+    pub fn get_miners_by_module(&self, module: Module, from_index: Option<u64>, limit: Option<u64>) -> Vec<AccountId> {
+        self.miners_by_module
+            .get(&module)
+            .map(|miners| Self::paginate(miners.iter().cloned(), from_index, limit))
+            .unwrap_or_default()
+    }
+
+    pub fn get_validators(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<AccountId> {
+        Self::paginate(self.validator_index.iter().cloned(), from_index, limit)
+    }
+
+    pub fn get_protocols(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<AccountId> {
+        Self::paginate(self.protocol_index.iter().cloned(), from_index, limit)
+    }
+
+    pub fn get_requests_by_protocol(&self, protocol: AccountId, from_index: Option<u64>, limit: Option<u64>) -> Vec<Hash> {
+        self.requests_by_protocol
+            .get(&protocol)
+            .map(|requests| Self::paginate(requests.iter().cloned(), from_index, limit))
+            .unwrap_or_default()
+    }
+
+    // @dev Requests that have not ended yet. Requests leave the index once they are settled or finalized, the page is taken
+    // from the index before the requests that ended in the meantime are filtered out, so it can be shorter than the limit.
+    pub fn get_active_requests(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<Hash> {
+        Self::paginate(self.active_requests.iter(), from_index, limit)
+            .into_iter()
+            .filter(|request_id| {
+                self.get_request(request_id)
+                    .is_some_and(|request| Self::get_stage(request) != RequestState::Ended)
+            })
+            .cloned()
+            .collect()
+    }
+
+    // @dev Miners that revealed their answer on the request, in the order they committed
+    pub fn get_revealed_miners(&self, request_id: Hash, from_index: Option<u64>, limit: Option<u64>) -> Option<Vec<AccountId>> {
+        let request = self.get_request(&request_id)?;
+
+        let revealed = request
+            .committed_miners
+            .iter()
+            .filter(|miner| request.miners_proposals.get(*miner).is_some_and(|proposal| proposal.is_revealed))
+            .cloned();
+
+        Some(Self::paginate(revealed, from_index, limit))
+    }
+}
+
+impl Contract {
+    pub(crate) fn index_request(&mut self, protocol: &AccountId, request_id: Hash) {
+        let requests = self
+            .requests_by_protocol
+            .entry(protocol.clone())
+            .or_insert_with(|| Vector::new(StorageKey::RequestsByProtocol { protocol: protocol.clone() }));
+        requests.push(request_id.clone());
+
+        *self.unfinished_requests.entry(protocol.clone()).or_insert(0) += 1;
+        self.active_requests.insert(request_id);
+    }
+
+    pub(crate) fn index_miner_modules(&mut self, miner: &AccountId, modules: &[Module]) {
+        for module in modules {
+            self.miners_by_module
+                .entry(*module)
+                .or_insert_with(|| IndexedSet::new(StorageKey::MinersByModule { module: *module }))
+                .insert(miner.clone());
+        }
+
+        self.miner_modules.insert(miner.clone(), modules.to_vec());
+    }
+
+    pub(crate) fn unindex_miner_modules(&mut self, miner: &AccountId) {
+        for module in self.miner_modules.remove(miner).unwrap_or_default() {
+            if let Some(miners) = self.miners_by_module.get_mut(&module) {
+                miners.remove(miner);
+            }
+        }
+    }
+
+    // @dev Keeps the first occurrence of every module, a miner has to serve at least one
+    pub(crate) fn distinct_modules(modules: Vec<Module>) -> Vec<Module> {
+        let mut distinct = Vec::new();
+        for module in modules {
+            if !distinct.contains(&module) {
+                distinct.push(module);
+            }
+        }
+
+        if distinct.is_empty() {
+            ContractError::MissingModules.panic();
+        }
+
+        distinct
+    }
+
+    fn paginate<T>(items: impl Iterator<Item = T>, from_index: Option<u64>, limit: Option<u64>) -> Vec<T> {
+        let from_index = from_index.unwrap_or(0) as usize;
+        let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+
+        items.skip(from_index).take(limit).collect()
+    }
+}
@@ -0,0 +1,95 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, AccountId, FunctionError};
+use std::fmt;
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum CommitError {
+    NotRegistered,
+    Unbonding,
+    NotSelected,
+    ModuleNotSupported,
+    RequestNotFound,
+    WrongStage,
+    AlreadyCommitted,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum RevealError {
+    NotRegistered,
+    RequestNotFound,
+    WrongStage,
+    ProposalNotFound,
+    AlreadyRevealed,
+    HashMismatch,
+    InvalidAnswer,
+    RepeatedAccount,
+    MinerNotRegistered,
+    MinerNotCommitted,
+    MinerNotRevealed,
+    InvalidSalt,
+    LegacyCommitmentDisabled,
+}
+
+// @dev Conditions that abort the call. Payable methods abort so the attached deposit is refunded.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ContractError {
+    InsufficientProtocolDeposit,
+    InsufficientMinerStake,
+    InsufficientValidatorStake,
+    InsufficientRequestFee,
+    PhaseDurationOutOfBounds,
+    ProtocolNotRegistered(AccountId),
+    RequestNotFound,
+    RequestNotEnded,
+    InvalidAnswer,
+    UnknownStateLayout,
+    ConfigOutOfBounds,
+    InvalidAnswerKind,
+    RankingSizeOutOfBounds,
+    InvalidScoringMode,
+    ZeroStakeAmount,
+    ModuleNotSupported,
+    MissingModules,
+    InvalidCultureProfile,
+    ProtocolNotMigrated(AccountId),
+}
+
+impl fmt::Display for ContractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InsufficientProtocolDeposit => write!(f, "Deposit is less than the required to register"),
+            Self::InsufficientMinerStake => write!(f, "Miner deposit is less than the minimum stake"),
+            Self::InsufficientValidatorStake => write!(f, "Validator deposit is less than the minimum stake"),
+            Self::InsufficientRequestFee => write!(f, "Deposit is less than the required to request a governance decision"),
+            Self::PhaseDurationOutOfBounds => write!(f, "Phase duration out of bounds"),
+            Self::ProtocolNotRegistered(account) => write!(f, "Account unregistered: {}", account),
+            Self::RequestNotFound => write!(f, "Request not found"),
+            Self::RequestNotEnded => write!(f, "Not stage ended"),
+            Self::InvalidAnswer => write!(f, "Invalid answer"),
+            Self::UnknownStateLayout => write!(f, "Contract state has an unknown layout"),
+            Self::ConfigOutOfBounds => write!(f, "Config value out of bounds"),
+            Self::InvalidAnswerKind => write!(f, "Invalid answer kind"),
+            Self::RankingSizeOutOfBounds => write!(f, "Ranking size out of bounds"),
+            Self::InvalidScoringMode => write!(f, "Invalid scoring mode"),
+            Self::ZeroStakeAmount => write!(f, "Stake amount must be greater than zero"),
+            Self::ModuleNotSupported => write!(f, "Module not supported by the protocol"),
+            Self::MissingModules => write!(f, "At least one module is required"),
+            Self::InvalidCultureProfile => write!(f, "Invalid culture profile"),
+            Self::ProtocolNotMigrated(account) => write!(f, "Protocol storage not migrated: {}", account),
+        }
+    }
+}
+
+// @dev Same as require!, outside wasm it is a regular panic so the message can be checked in tests
+impl FunctionError for ContractError {
+    fn panic(&self) -> ! {
+        if cfg!(target_arch = "wasm32") {
+            env::panic_str(&self.to_string())
+        } else {
+            panic!("{}", self)
+        }
+    }
+}
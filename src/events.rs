@@ -1,7 +1,9 @@
 use near_sdk::serde::{Deserialize, Serialize};
-use near_sdk::AccountId;
+use near_sdk::{AccountId, NearToken};
 use std::fmt;
 
+use crate::models::{Answer, Config, CultureProfile, Module, Participant, Role, Votes};
+
 type Hash = String;
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -18,7 +20,26 @@ pub enum EventLogVariant {
     CommitValidator(Vec<CommitValidatorLog>),
     RevealMiner(Vec<RevealMinerLog>),
     RevealValidator(Vec<RevealValidatorLog>),
-    ToptenMiners(Vec<ToptenMinersLog>),
+    TopMiners(Vec<TopMinersLog>),
+    RequestUnstake(Vec<RequestUnstakeLog>),
+    WithdrawStake(Vec<WithdrawStakeLog>),
+    AddStake(Vec<AddStakeLog>),
+    DecreaseStake(Vec<DecreaseStakeLog>),
+    Slash(Vec<SlashLog>),
+    RewardDistributed(Vec<RewardDistributedLog>),
+    ClaimRewards(Vec<ClaimRewardsLog>),
+    RequestFinalized(Vec<RequestFinalizedLog>),
+    OwnershipTransferred(Vec<OwnershipTransferredLog>),
+    RoleGranted(Vec<RoleGrantedLog>),
+    RoleRevoked(Vec<RoleRevokedLog>),
+    Pause(Vec<PauseLog>),
+    Unpause(Vec<UnpauseLog>),
+    ConfigUpdated(Vec<ConfigUpdatedLog>),
+    MinerModulesUpdated(Vec<MinerModulesUpdatedLog>),
+    ProtocolUpdated(Vec<ProtocolUpdatedLog>),
+    ModuleAdded(Vec<ModuleAddedLog>),
+    ModuleRemoved(Vec<ModuleRemovedLog>),
+    ProtocolDeregistered(Vec<ProtocolDeregisteredLog>),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -33,7 +54,7 @@ pub struct EventLog {
 
 impl fmt::Display for EventLog {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("EVENT_JSON:{}", &serde_json::to_string(self).map_err(|_| fmt::Error)?))
+        f.write_fmt(format_args!("EVENT_JSON:{}", serde_json::to_string(self).map_err(|_| fmt::Error)?))
     }
 }
 
@@ -47,6 +68,45 @@ pub struct RegisterProtocolLog {
 #[serde(crate = "near_sdk::serde")]
 pub struct RegisterMinerLog {
     pub miner: AccountId,
+    pub modules: Vec<Module>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MinerModulesUpdatedLog {
+    pub miner: AccountId,
+    pub modules: Vec<Module>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProtocolUpdatedLog {
+    pub account: AccountId,
+    pub version: u32,
+    pub culture: CultureProfile,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ModuleAddedLog {
+    pub account: AccountId,
+    pub version: u32,
+    pub module: Module,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ModuleRemovedLog {
+    pub account: AccountId,
+    pub version: u32,
+    pub module: Module,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProtocolDeregisteredLog {
+    pub account: AccountId,
+    pub refund: NearToken,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -59,6 +119,8 @@ pub struct RegisterValidatorLog {
 #[serde(crate = "near_sdk::serde")]
 pub struct RegisterRequestLog {
     pub request_id: String,
+    pub culture: Option<CultureProfile>,
+    pub protocol_version: u32,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -79,7 +141,7 @@ pub struct CommitValidatorLog {
 #[serde(crate = "near_sdk::serde")]
 pub struct RevealMinerLog {
     pub request_id: String,
-    pub answer: bool,
+    pub answer: Answer,
     pub message: String,
 }
 
@@ -93,7 +155,122 @@ pub struct RevealValidatorLog {
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(crate = "near_sdk::serde")]
-pub struct ToptenMinersLog {
+pub struct TopMinersLog {
+    pub request_id: String,
+    pub ranking_size: u32,
+    pub top_miners: Vec<(AccountId, Votes)>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RequestUnstakeLog {
+    pub account: AccountId,
+    pub participant: Participant,
+    pub amount: NearToken,
+    pub available_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct WithdrawStakeLog {
+    pub account: AccountId,
+    pub participant: Participant,
+    pub amount: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AddStakeLog {
+    pub account: AccountId,
+    pub participant: Participant,
+    pub amount: NearToken,
+    pub stake: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DecreaseStakeLog {
+    pub account: AccountId,
+    pub participant: Participant,
+    pub amount: NearToken,
+    pub stake: NearToken,
+    pub available_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SlashLog {
+    pub request_id: String,
+    pub account: AccountId,
+    pub participant: Participant,
+    pub amount: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RewardDistributedLog {
+    pub request_id: String,
+    pub account: AccountId,
+    pub amount: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ClaimRewardsLog {
+    pub account: AccountId,
+    pub amount: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RequestFinalizedLog {
     pub request_id: String,
-    pub topten: Vec<(AccountId, i32)>,
+    pub decision: Option<Answer>,
+    pub answers: u32,
+    pub support: u32,
+    pub revealed_miners: u32,
+    pub revealed_validators: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OwnershipTransferredLog {
+    pub previous_owner: AccountId,
+    pub new_owner: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RoleGrantedLog {
+    pub role: Role,
+    pub account: AccountId,
+    pub sender: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RoleRevokedLog {
+    pub role: Role,
+    pub account: AccountId,
+    pub sender: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PauseLog {
+    pub account: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct UnpauseLog {
+    pub account: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ConfigUpdatedLog {
+    pub sender: AccountId,
+    pub previous: Config,
+    pub config: Config,
 }
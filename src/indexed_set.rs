@@ -0,0 +1,77 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::store::{LookupMap, Vector};
+use near_sdk::IntoStorageKey;
+use std::ops::Range;
+
+// @dev Set that can be paginated and read by position, built on the stable collections of near-sdk.
+// Values keep their insertion order: a removed value leaves its position empty, so the other values never move.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct IndexedSet<T>
+where
+    T: BorshSerialize + BorshDeserialize + Ord + Clone,
+{
+    slots: Vector<Option<T>>,
+    positions: LookupMap<T, u32>,
+    // @dev Every position before it is empty, so listings don't read them again
+    first: u32,
+}
+
+impl<T> IndexedSet<T>
+where
+    T: BorshSerialize + BorshDeserialize + Ord + Clone,
+{
+    pub fn new<S: IntoStorageKey>(prefix: S) -> Self {
+        let prefix = prefix.into_storage_key();
+
+        Self {
+            slots: Vector::new([prefix.as_slice(), b"v"].concat()),
+            positions: LookupMap::new([prefix.as_slice(), b"p"].concat()),
+            first: 0,
+        }
+    }
+
+    // @dev Positions that can hold a value, some of them may be empty
+    pub fn positions(&self) -> Range<u32> {
+        self.first..self.slots.len()
+    }
+
+    pub fn get(&self, position: u32) -> Option<&T> {
+        self.slots.get(position).and_then(Option::as_ref)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.positions.contains_key(value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.slots.iter().skip(self.first as usize).flatten()
+    }
+
+    pub fn insert(&mut self, value: T) -> bool {
+        if self.contains(&value) {
+            return false;
+        }
+
+        self.positions.insert(value.clone(), self.slots.len());
+        self.slots.push(Some(value));
+        true
+    }
+
+    pub fn remove(&mut self, value: &T) -> bool {
+        let Some(position) = self.positions.remove(value) else {
+            return false;
+        };
+
+        self.slots.set(position, None);
+
+        // @dev Empty positions at both ends are dropped, values are never moved
+        while self.slots.len() > self.first && self.get(self.slots.len() - 1).is_none() {
+            self.slots.pop();
+        }
+        while self.first < self.slots.len() && self.get(self.first).is_none() {
+            self.first += 1;
+        }
+
+        true
+    }
+}
@@ -1,63 +1,139 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::store::LookupMap;
-use near_sdk::{env, log, near_bindgen, require, AccountId, PanicOnDefault};
+use near_sdk::store::{LookupMap, LookupSet, Vector};
+use near_sdk::{env, log, near_bindgen, AccountId, FunctionError, NearToken, PanicOnDefault};
 use std::collections::HashSet;
 
+use crate::indexed_set::IndexedSet;
+
+pub use crate::client::*;
 pub use crate::constants::*;
+pub use crate::errors::*;
 pub use crate::events::*;
 pub use crate::models::*;
+pub use earthmind_commitment::*;
 
+mod access_control;
+mod client;
+mod committee;
+mod config;
 mod constants;
+mod decision;
+mod enumeration;
+mod errors;
 mod events;
+mod indexed_set;
+mod migration;
 mod models;
+mod protocol;
+mod rewards;
+mod settlement;
+mod staking;
+mod views;
 
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
 pub struct Contract {
-    protocols: LookupMap<AccountId, Protocol>,
-    requests: LookupMap<Hash, Request>,
+    state_version: StateVersion,
+    protocols: LookupMap<AccountId, VersionedProtocol>,
+    requests: LookupMap<Hash, VersionedRequest>,
     miners: LookupMap<AccountId, Stake>,
+    miner_modules: LookupMap<AccountId, Vec<Module>>,
+    miners_by_module: LookupMap<Module, IndexedSet<AccountId>>,
     validators: LookupMap<AccountId, Stake>,
+    unbonding: LookupMap<(Participant, AccountId), u64>,
+    stake_decreases: LookupMap<(Participant, AccountId), Vec<StakeDecrease>>,
+    open_commits: LookupMap<(Participant, AccountId), Vec<Hash>>,
+    rewards: LookupMap<AccountId, NearToken>,
+    miner_index: IndexedSet<AccountId>,
+    validator_index: IndexedSet<AccountId>,
+    protocol_index: IndexedSet<AccountId>,
+    requests_by_protocol: LookupMap<AccountId, Vector<Hash>>,
+    unfinished_requests: LookupMap<AccountId, u32>,
+    active_requests: IndexedSet<Hash>,
+    owner_id: AccountId,
+    roles: LookupSet<(Role, AccountId)>,
+    paused: bool,
+    pause_periods: Vector<PausePeriod>,
+    config: Config,
 }
 
 #[near_bindgen]
 impl Contract {
     #[allow(clippy::use_self)]
     #[init]
-    pub fn new() -> Self {
+    pub fn new(owner_id: AccountId, config: Config) -> Self {
+        if !config.is_within_bounds() {
+            ContractError::ConfigOutOfBounds.panic();
+        }
+
         Self {
-            protocols: LookupMap::new(b"protocols".to_vec()),
-            requests: LookupMap::new(b"requests".to_vec()),
+            state_version: StateVersion::V1,
+            protocols: LookupMap::new(PROTOCOLS_PREFIX),
+            requests: LookupMap::new(REQUESTS_PREFIX),
             miners: LookupMap::new(b"miners".to_vec()),
+            miner_modules: LookupMap::new(MINER_MODULES_PREFIX),
+            miners_by_module: LookupMap::new(MINERS_BY_MODULE_PREFIX),
             validators: LookupMap::new(b"validators".to_vec()),
+            unbonding: LookupMap::new(b"unbonding".to_vec()),
+            stake_decreases: LookupMap::new(STAKE_DECREASES_PREFIX),
+            open_commits: LookupMap::new(b"open_commits".to_vec()),
+            rewards: LookupMap::new(b"rewards".to_vec()),
+            miner_index: IndexedSet::new(b"miner_index".to_vec()),
+            validator_index: IndexedSet::new(b"validator_index".to_vec()),
+            protocol_index: IndexedSet::new(b"protocol_index".to_vec()),
+            requests_by_protocol: LookupMap::new(b"requests_by_protocol".to_vec()),
+            unfinished_requests: LookupMap::new(b"unfinished_requests".to_vec()),
+            active_requests: IndexedSet::new(b"active_requests".to_vec()),
+            owner_id,
+            roles: LookupSet::new(b"roles".to_vec()),
+            paused: false,
+            pause_periods: Vector::new(b"pause_periods".to_vec()),
+            config,
         }
     }
 
-    pub fn register_protocol(&mut self, culture: String, modules: Vec<Module>) -> RegisterProtocolResult {
+    #[payable]
+    pub fn register_protocol(&mut self, culture: CultureProfile, modules: Vec<Module>, timing: Option<PhaseDurations>) -> RegisterProtocolResult {
+        self.assert_not_paused();
+
         let new_account = env::predecessor_account_id();
         let registration_fee = env::attached_deposit();
 
-        if registration_fee < PROTOCOL_REGISTRATION_FEE {
-            panic!("Deposit is less than the required to register");
+        if registration_fee < self.config.protocol_registration_fee {
+            ContractError::InsufficientProtocolDeposit.panic();
+        }
+
+        // @dev The timing is the default profile for every request of the protocol
+        let timing = timing.unwrap_or_default();
+        if !timing.is_within_bounds() {
+            ContractError::PhaseDurationOutOfBounds.panic();
+        }
+
+        if !culture.is_valid() {
+            ContractError::InvalidCultureProfile.panic();
         }
 
         if self.is_protocol_registered(new_account.clone()) {
             log!("Attempted to register an already registered account: {}", new_account);
+            Self::refund_deposit(new_account, registration_fee);
             return RegisterProtocolResult::AlreadyRegistered;
         }
 
         let new_protocol = Protocol {
             account: new_account.clone(),
-            culture,
+            culture: Some(culture),
             modules,
             registration_fee,
+            timing,
+            version: 1,
         };
 
-        self.protocols.insert(new_account.clone(), new_protocol);
+        self.protocols.insert(new_account.clone(), new_protocol.into());
+        self.protocol_index.insert(new_account.clone());
 
         let register_protocol_log = EventLog {
             standard: "emip001".to_string(),
-            version: "1.0.0".to_string(),
+            version: "2.0.0".to_string(),
             event: EventLogVariant::RegisterProtocol(vec![RegisterProtocolLog { account: new_account }]),
         };
 
@@ -70,26 +146,35 @@ impl Contract {
         self.protocols.contains_key(&account)
     }
 
-    pub fn register_miner(&mut self) -> RegisterMinerResult {
+    // @dev Miners declare the modules they can serve, they can only commit to requests for one of them
+    #[payable]
+    pub fn register_miner(&mut self, modules: Vec<Module>) -> RegisterMinerResult {
+        self.assert_not_paused();
+
         let new_miner_id = env::predecessor_account_id();
         let deposit = env::attached_deposit();
 
-        if deposit < MIN_MINER_STAKE {
-            panic!("Miner deposit is less than the minimum stake");
+        if deposit < self.config.min_miner_stake {
+            ContractError::InsufficientMinerStake.panic();
         }
 
-        // @dev Validate the miner is not already registered
+        let modules = Self::distinct_modules(modules);
+
+        // @dev Validate the miner is not already registered, use add_stake to top up the stake
         if self.is_miner_registered(new_miner_id.clone()) {
             log!("Attempted to register an already registered miner: {}", new_miner_id);
+            Self::refund_deposit(new_miner_id, deposit);
             return RegisterMinerResult::AlreadyRegistered;
         }
 
         self.miners.insert(new_miner_id.clone(), deposit);
+        self.miner_index.insert(new_miner_id.clone());
+        self.index_miner_modules(&new_miner_id, &modules);
 
         let register_miner_log = EventLog {
             standard: "emip001".to_string(),
-            version: "1.0.0".to_string(),
-            event: EventLogVariant::RegisterMiner(vec![RegisterMinerLog { miner: new_miner_id }]),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::RegisterMiner(vec![RegisterMinerLog { miner: new_miner_id, modules }]),
         };
 
         log!(&register_miner_log.to_string());
@@ -101,24 +186,58 @@ impl Contract {
         self.miners.contains_key(&miner_id)
     }
 
+    // @dev Replaces the modules of a registered miner, miners registered before modules were declared have none
+    pub fn update_miner_modules(&mut self, modules: Vec<Module>) -> UpdateMinerModulesResult {
+        self.assert_not_paused();
+
+        let miner = env::predecessor_account_id();
+        if !self.is_miner_registered(miner.clone()) {
+            log!("Miner not registered: {}", miner);
+            return UpdateMinerModulesResult::NotRegistered;
+        }
+
+        let modules = Self::distinct_modules(modules);
+        self.unindex_miner_modules(&miner);
+        self.index_miner_modules(&miner, &modules);
+
+        let miner_modules_updated_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::MinerModulesUpdated(vec![MinerModulesUpdatedLog { miner, modules }]),
+        };
+
+        log!(&miner_modules_updated_log.to_string());
+
+        UpdateMinerModulesResult::Success
+    }
+
+    pub fn get_miner_modules(&self, miner_id: AccountId) -> Vec<Module> {
+        self.miner_modules.get(&miner_id).cloned().unwrap_or_default()
+    }
+
+    #[payable]
     pub fn register_validator(&mut self) -> RegisterValidatorResult {
+        self.assert_not_paused();
+
         let new_validator_id = env::predecessor_account_id();
         let deposit = env::attached_deposit();
 
-        if deposit < MIN_VALIDATOR_STAKE {
-            panic!("Validator deposit is less than the minimum stake");
+        if deposit < self.config.min_validator_stake {
+            ContractError::InsufficientValidatorStake.panic();
         }
 
         if self.is_validator_registered(new_validator_id.clone()) {
             log!("Attempted to register an already registered validator: {}", new_validator_id);
+            Self::refund_deposit(new_validator_id, deposit);
             return RegisterValidatorResult::AlreadyRegistered;
         }
 
         self.validators.insert(new_validator_id.clone(), deposit);
+        self.validator_index.insert(new_validator_id.clone());
 
         let register_validator_log = EventLog {
             standard: "emip001".to_string(),
-            version: "1.0.0".to_string(),
+            version: "2.0.0".to_string(),
             event: EventLogVariant::RegisterValidator(vec![RegisterValidatorLog { validator: new_validator_id }]),
         };
 
@@ -131,43 +250,125 @@ impl Contract {
         self.validators.contains_key(&validator_id)
     }
 
-    pub fn request_governance_decision(&mut self, message: String) -> RegisterRequestResult {
+    // @dev The attached deposit funds the reward pool of the request. Requests without an answer kind are yes or no questions.
+    #[payable]
+    pub fn request_governance_decision(
+        &mut self,
+        message: String,
+        timing: Option<PhaseDurations>,
+        answer_kind: Option<AnswerKind>,
+        ranking_size: Option<u32>,
+        scoring: Option<ScoringMode>,
+        module: Option<Module>,
+    ) -> RegisterRequestResult {
+        self.assert_not_paused();
+
         let sender_account = env::predecessor_account_id();
+        let reward_pool = env::attached_deposit();
 
-        let concatenated_answer = format!("{}{}", sender_account, message);
-        let new_request_id = env::keccak256(concatenated_answer.as_bytes());
-        let new_request_id_hex = hex::encode(new_request_id);
+        let nonce = self.get_request_nonce(sender_account.clone());
+        let new_request_id_hex = hex::encode(Self::request_id(&sender_account, &message, nonce));
 
         //@dev verify that user is registerd in the protocol
-        if !self.is_protocol_registered(sender_account.clone()) {
-            panic!("Account unregistered: {}", sender_account);
+        if Self::is_unmigrated_protocol(&sender_account) {
+            ContractError::ProtocolNotMigrated(sender_account).panic();
+        }
+        let Some(protocol) = self.protocols.get(&sender_account).map(VersionedProtocol::current) else {
+            ContractError::ProtocolNotRegistered(sender_account).panic();
+        };
+
+        if reward_pool < self.config.min_request_fee {
+            ContractError::InsufficientRequestFee.panic();
+        }
+
+        // @dev A request can override the timing profile of its protocol
+        let timing = timing.unwrap_or(protocol.timing);
+        if !timing.is_within_bounds() {
+            ContractError::PhaseDurationOutOfBounds.panic();
+        }
+
+        // @dev Requests that don't name a module need the first module of the protocol
+        let Some(module) = module
+            .or_else(|| protocol.modules.first().copied())
+            .filter(|module| protocol.modules.contains(module))
+        else {
+            ContractError::ModuleNotSupported.panic();
+        };
+
+        let answer_kind = answer_kind.unwrap_or_default();
+        if !answer_kind.is_valid() {
+            ContractError::InvalidAnswerKind.panic();
+        }
+
+        // @dev Number of miners validators rank and that make the top miners of the request
+        let ranking_size = ranking_size.unwrap_or_else(|| self.config.default_ranking_size());
+        if !self.config.accepts_ranking_size(ranking_size) {
+            ContractError::RankingSizeOutOfBounds.panic();
+        }
+
+        let scoring = scoring.unwrap_or_default();
+        if !scoring.is_valid_for(ranking_size) {
+            ContractError::InvalidScoringMode.panic();
         }
 
         //@dev Validate the request is not already registered
         if self.get_request_by_id(new_request_id_hex.clone()) {
             log!("Attempted to register an already registered request: {}", new_request_id_hex);
+            Self::refund_deposit(sender_account, reward_pool);
             return RegisterRequestResult::AlreadyRegistered;
         }
 
+        // @dev Without a committee config any registered account can join the request
+        let committee = self.config.committee.map(|config| self.select_committee(&new_request_id_hex, config, module));
+
         let new_request = Request {
-            sender: sender_account,
+            sender: sender_account.clone(),
             request_id: new_request_id_hex.clone(),
             start_time: env::block_timestamp(),
-            miners_proposals: LookupMap::new(b"miner_proposal".to_vec()),
-            validators_proposals: LookupMap::new(b"validator_proposal".to_vec()),
-            votes_for_miners: LookupMap::new(b"votes_miners".to_vec()),
+            timing,
+            answer_kind,
+            ranking_size,
+            scoring,
+            vote_weighting: self.config.vote_weighting,
+            committee,
+            module: Some(module),
+            // @dev Snapshot of the protocol, requests in flight keep it when the protocol is updated
+            culture: protocol.culture.clone(),
+            protocol_version: protocol.version,
+            miners_proposals: LookupMap::new(StorageKey::MinerProposals {
+                request_id: new_request_id_hex.clone(),
+            }),
+            validators_proposals: LookupMap::new(StorageKey::ValidatorProposals {
+                request_id: new_request_id_hex.clone(),
+            }),
+            votes_for_miners: LookupMap::new(StorageKey::VotesForMiners {
+                request_id: new_request_id_hex.clone(),
+            }),
             miner_keys: Vec::new(),
-            top_ten: Vec::new(),
+            top_miners: Vec::new(),
+            committed_miners: Vec::new(),
+            committed_validators: Vec::new(),
+            is_settled: false,
+            reward_pool,
+            rewards_distributed: false,
+            result: None,
+            delivery: None,
         };
 
+        let culture = new_request.culture.clone();
+        let protocol_version = new_request.protocol_version;
+
         // @dev We store the key of the request as the hash of the message
-        self.requests.insert(new_request_id_hex.clone(), new_request);
+        self.requests.insert(new_request_id_hex.clone(), new_request.into());
+        self.index_request(&sender_account, new_request_id_hex.clone());
 
         let register_request_log = EventLog {
             standard: "emip001".to_string(),
-            version: "1.0.0".to_string(),
+            version: "2.0.0".to_string(),
             event: EventLogVariant::RegisterRequest(vec![RegisterRequestLog {
                 request_id: new_request_id_hex,
+                culture,
+                protocol_version,
             }]),
         };
 
@@ -180,120 +381,200 @@ impl Contract {
         self.requests.contains_key(&request_id)
     }
 
+    // @dev A reveal without salt is checked against the legacy format, if it is still accepted
+    fn check_salt(&self, salt: Option<&String>) -> Result<(), RevealError> {
+        let version = CommitmentVersion::from_salt(salt);
+
+        if !self.config.accepts_commitment(version) {
+            log!("Legacy commitments are not accepted");
+            return Err(RevealError::LegacyCommitmentDisabled);
+        }
+
+        if salt.is_some_and(|salt| salt.len() < MIN_SALT_LENGTH) {
+            log!("Salt is shorter than {} characters", MIN_SALT_LENGTH);
+            return Err(RevealError::InvalidSalt);
+        }
+
+        Ok(())
+    }
+
+    // @dev The first request of a protocol keeps the id of the first version, later requests fold their nonce in
+    // so a protocol can ask the same question again
+    fn request_id(sender: &AccountId, message: &str, nonce: u64) -> Vec<u8> {
+        let request_id = env::keccak256(format!("{}{}", sender, message).as_bytes());
+        if nonce == 0 {
+            return request_id;
+        }
+
+        env::keccak256(&[request_id, nonce.to_be_bytes().to_vec()].concat())
+    }
+
+    fn get_request(&self, request_id: &Hash) -> Option<&Request> {
+        self.requests.get(request_id).map(VersionedRequest::current)
+    }
+
     fn get_request_by_id_mut(&mut self, request_id: Hash) -> Option<&mut Request> {
-        self.requests.get_mut(&request_id)
+        self.requests.get_mut(&request_id).map(VersionedRequest::current_mut)
     }
 
-    fn get_stage(start_time: u64) -> RequestState {
+    fn get_stage(request: &Request) -> RequestState {
+        Self::stage_at(request.start_time, request.timing)
+    }
+
+    fn stage_at(start_time: u64, timing: PhaseDurations) -> RequestState {
         let elapsed = env::block_timestamp() - start_time;
 
         if start_time == 0 {
             RequestState::NonStarted
-        } else if elapsed < COMMIT_MINER_DURATION {
+        } else if elapsed < timing.commit_miner {
             RequestState::CommitMiners
-        } else if elapsed < COMMIT_MINER_DURATION + REVEAL_MINER_DURATION {
+        } else if elapsed < timing.commit_miner + timing.reveal_miner {
             RequestState::RevealMiners
-        } else if elapsed < COMMIT_MINER_DURATION + REVEAL_MINER_DURATION + COMMIT_VALIDATOR_DURATION {
+        } else if elapsed < timing.commit_miner + timing.reveal_miner + timing.commit_validator {
             RequestState::CommitValidators
-        } else if elapsed < COMMIT_MINER_DURATION + REVEAL_MINER_DURATION + COMMIT_VALIDATOR_DURATION + REVEAL_VALIDATOR_DURATION {
+        } else if elapsed < timing.commit_miner + timing.reveal_miner + timing.commit_validator + timing.reveal_validator {
             RequestState::RevealValidators
         } else {
             RequestState::Ended
         }
     }
 
-    pub fn hash_miner_answer(self, request_id: Hash, answer: bool, message: String) -> Hash {
-        let miner = env::predecessor_account_id();
-
-        let concatenated_answer = format!("{}{}{}{}", request_id, miner, answer, message);
-        let value = env::keccak256(concatenated_answer.as_bytes());
-
-        //@dev Return the hash of the answer
-        hex::encode(value)
+    // @dev View helper, the miner is passed explicitly so it doesn't depend on the caller.
+    // Without a salt the legacy format is returned, which only supports yes or no answers.
+    pub fn hash_miner_answer(&self, request_id: Hash, miner: AccountId, answer: Answer, message: String, salt: Option<String>) -> Hash {
+        match (salt, &answer) {
+            (Some(salt), _) => hash_miner_commitment(&request_id, miner.as_str(), (&answer).into(), &message, &salt),
+            (None, Answer::YesNo(answer)) => hash_legacy_miner_answer(&request_id, miner.as_str(), *answer, &message),
+            (None, _) => ContractError::InvalidAnswer.panic(),
+        }
     }
 
     pub fn commit_by_miner(&mut self, request_id: Hash, answer: Hash) -> CommitMinerResult {
+        self.assert_not_paused();
+
         let miner = env::predecessor_account_id();
 
         if !self.is_miner_registered(miner.clone()) {
             log!("Miner not registered: {}", miner);
-            return CommitMinerResult::Fail;
+            return CommitMinerResult::Fail(CommitError::NotRegistered);
+        }
+
+        if self.is_unbonding(Participant::Miner, miner.clone()) {
+            log!("Miner is unbonding: {}", miner);
+            return CommitMinerResult::Fail(CommitError::Unbonding);
         }
 
+        let miner_modules = self.get_miner_modules(miner.clone());
+
         match self.get_request_by_id_mut(request_id.clone()) {
             Some(request) => {
-                assert_eq!(Self::get_stage(request.start_time), RequestState::CommitMiners, "Not at CommitMiners stage");
+                if Self::get_stage(request) != RequestState::CommitMiners {
+                    log!("Not at CommitMiners stage");
+                    return CommitMinerResult::Fail(CommitError::WrongStage);
+                }
+
+                if request
+                    .committee
+                    .as_ref()
+                    .is_some_and(|committee| !committee.contains(Participant::Miner, &miner))
+                {
+                    log!("Miner not selected: {}", miner);
+                    return CommitMinerResult::Fail(CommitError::NotSelected);
+                }
+
+                if request.module.is_some_and(|module| !miner_modules.contains(&module)) {
+                    log!("Miner doesn't support the module of the request: {}", miner);
+                    return CommitMinerResult::Fail(CommitError::ModuleNotSupported);
+                }
 
                 if request.miners_proposals.get(&miner).is_some() {
                     log!("This miner have a commit answer: {}", miner);
-                    return CommitMinerResult::Fail;
+                    return CommitMinerResult::Fail(CommitError::AlreadyCommitted);
                 }
 
                 let proposal = MinerProposal {
                     proposal_hash: answer.clone(),
-                    answer: false,
+                    answer: None,
                     is_revealed: false,
                 };
 
                 // @dev Insert miners_proposals using a mut reference
-                request.miners_proposals.insert(miner, proposal);
+                request.miners_proposals.insert(miner.clone(), proposal);
+                request.committed_miners.push(miner.clone());
 
                 let commit_miner_log = EventLog {
                     standard: "emip001".to_string(),
-                    version: "1.0.0".to_string(),
-                    event: EventLogVariant::CommitMiner(vec![CommitMinerLog { request_id, answer }]),
+                    version: "2.0.0".to_string(),
+                    event: EventLogVariant::CommitMiner(vec![CommitMinerLog {
+                        request_id: request_id.clone(),
+                        answer,
+                    }]),
                 };
 
                 log!(&commit_miner_log.to_string());
-
-                CommitMinerResult::Success
             }
             None => {
                 log!("Request is not registered: {}", request_id);
-                CommitMinerResult::Fail
+                return CommitMinerResult::Fail(CommitError::RequestNotFound);
             }
         }
-    }
 
-    pub fn hash_validator_answer(self, request_id: String, answer: Vec<AccountId>, message: String) -> Hash {
-        let validator = env::predecessor_account_id();
+        // @dev Keep track of the commit so the stake can't be withdrawn while the request is open
+        self.track_open_commit(Participant::Miner, miner, request_id);
 
-        require!(answer.len() == 10, "Invalid answer");
-
-        let mut concatenated_answer: Vec<u8> = Vec::new();
-
-        concatenated_answer.extend_from_slice(request_id.as_bytes());
-        concatenated_answer.extend_from_slice(validator.as_bytes());
+        CommitMinerResult::Success
+    }
 
-        let value: Vec<u8> = answer.iter().flat_map(|id| id.as_bytes()).copied().collect();
-        concatenated_answer.extend_from_slice(&value);
-        concatenated_answer.extend_from_slice(message.as_bytes());
+    // @dev The answer has to rank as many miners as the ranking size of the request
+    pub fn hash_validator_answer(&self, request_id: Hash, validator: AccountId, answer: Vec<AccountId>, message: String, salt: Option<String>) -> Hash {
+        let Some(request) = self.get_request(&request_id) else {
+            ContractError::RequestNotFound.panic();
+        };
 
-        let value = env::keccak256(&concatenated_answer);
+        if answer.len() != request.ranking_size as usize {
+            ContractError::InvalidAnswer.panic();
+        }
 
-        //@dev Return the hash of the answer
-        hex::encode(value)
+        salt.map_or_else(
+            || hash_legacy_validator_answer(&request_id, validator.as_str(), &answer, &message),
+            |salt| hash_validator_commitment(&request_id, validator.as_str(), &answer, &message, &salt),
+        )
     }
 
     pub fn commit_by_validator(&mut self, request_id: String, answer: Hash) -> CommitValidatorResult {
+        self.assert_not_paused();
+
         let validator = env::predecessor_account_id();
 
         if !self.is_validator_registered(validator.clone()) {
             log!("Validator is not registered: {}", validator);
-            return CommitValidatorResult::Fail;
+            return CommitValidatorResult::Fail(CommitError::NotRegistered);
+        }
+
+        if self.is_unbonding(Participant::Validator, validator.clone()) {
+            log!("Validator is unbonding: {}", validator);
+            return CommitValidatorResult::Fail(CommitError::Unbonding);
         }
 
         match self.get_request_by_id_mut(request_id.clone()) {
             Some(request) => {
-                assert_eq!(
-                    Self::get_stage(request.start_time),
-                    RequestState::CommitValidators,
-                    "Not at CommitValidator stage"
-                );
+                if Self::get_stage(request) != RequestState::CommitValidators {
+                    log!("Not at CommitValidator stage");
+                    return CommitValidatorResult::Fail(CommitError::WrongStage);
+                }
+
+                if request
+                    .committee
+                    .as_ref()
+                    .is_some_and(|committee| !committee.contains(Participant::Validator, &validator))
+                {
+                    log!("Validator not selected: {}", validator);
+                    return CommitValidatorResult::Fail(CommitError::NotSelected);
+                }
 
                 if request.validators_proposals.get(&validator).is_some() {
                     log!("This validator have a commit answer: {}", validator);
-                    return CommitValidatorResult::Fail;
+                    return CommitValidatorResult::Fail(CommitError::AlreadyCommitted);
                 }
 
                 let proposal = ValidatorProposal {
@@ -303,73 +584,90 @@ impl Contract {
                 };
 
                 // @dev Insert miners_proposals using a mut reference
-                request.validators_proposals.insert(validator, proposal);
+                request.validators_proposals.insert(validator.clone(), proposal);
+                request.committed_validators.push(validator.clone());
 
                 let commit_validator_log = EventLog {
                     standard: "emip001".to_string(),
-                    version: "1.0.0".to_string(),
-                    event: EventLogVariant::CommitValidator(vec![CommitValidatorLog { request_id, answer }]),
+                    version: "2.0.0".to_string(),
+                    event: EventLogVariant::CommitValidator(vec![CommitValidatorLog {
+                        request_id: request_id.clone(),
+                        answer,
+                    }]),
                 };
 
                 log!(&commit_validator_log.to_string());
-
-                CommitValidatorResult::Success
             }
             None => {
                 log!("Request is not registered: {}", request_id);
-                CommitValidatorResult::Fail
+                return CommitValidatorResult::Fail(CommitError::RequestNotFound);
             }
         }
+
+        self.track_open_commit(Participant::Validator, validator, request_id);
+
+        CommitValidatorResult::Success
     }
 
-    pub fn reveal_by_miner(&mut self, request_id: String, answer: bool, message: String) -> RevealMinerResult {
+    pub fn reveal_by_miner(&mut self, request_id: String, answer: Answer, message: String, salt: Option<String>) -> RevealMinerResult {
+        self.assert_not_paused();
+
         let miner = env::predecessor_account_id();
 
         if !self.is_miner_registered(miner.clone()) {
             log!("Miner not registered: {}", miner);
-            return RevealMinerResult::Fail;
+            return RevealMinerResult::Fail(RevealError::NotRegistered);
         }
 
-        if self.get_request_by_id_mut(request_id.clone()).is_none() {
-            log!("Request is not registered: {}", request_id);
-            return RevealMinerResult::Fail;
+        if let Err(error) = self.check_salt(salt.as_ref()) {
+            return RevealMinerResult::Fail(error);
         }
 
-        let complete_request = self
-            .get_request_by_id_mut(request_id.clone())
-            .map_or_else(|| panic!("Request not found"), |request| request);
+        let Some(complete_request) = self.get_request_by_id_mut(request_id.clone()) else {
+            log!("Request is not registered: {}", request_id);
+            return RevealMinerResult::Fail(RevealError::RequestNotFound);
+        };
 
-        assert_eq!(
-            Self::get_stage(complete_request.start_time),
-            RequestState::RevealMiners,
-            "Not at RevealMiners stage"
-        );
+        if Self::get_stage(complete_request) != RequestState::RevealMiners {
+            log!("Not at RevealMiners stage");
+            return RevealMinerResult::Fail(RevealError::WrongStage);
+        }
 
-        let save_proposal = complete_request
-            .miners_proposals
-            .get_mut(&miner)
-            .map_or_else(|| panic!("proposal not found"), |proposal| proposal);
+        let Some(save_proposal) = complete_request.miners_proposals.get_mut(&miner) else {
+            log!("proposal not found");
+            return RevealMinerResult::Fail(RevealError::ProposalNotFound);
+        };
 
         if save_proposal.is_revealed {
             log!("Proposal already revealed");
-            return RevealMinerResult::Fail;
+            return RevealMinerResult::Fail(RevealError::AlreadyRevealed);
         }
 
-        let concatenated_answer = format!("{}{}{}{}", request_id, miner, answer, message);
-        let hash_value = env::keccak256(concatenated_answer.as_bytes());
-        let answer_to_verify = hex::encode(hash_value);
+        if !complete_request.answer_kind.accepts(&answer) {
+            log!("Answer doesn't match the kind of the request");
+            return RevealMinerResult::Fail(RevealError::InvalidAnswer);
+        }
+
+        let answer_to_verify = match (salt.as_ref(), &answer) {
+            (Some(salt), _) => hash_miner_commitment(&request_id, miner.as_str(), (&answer).into(), &message, salt),
+            (None, Answer::YesNo(answer)) => hash_legacy_miner_answer(&request_id, miner.as_str(), *answer, &message),
+            (None, _) => {
+                log!("Legacy commitments only support yes or no answers");
+                return RevealMinerResult::Fail(RevealError::InvalidAnswer);
+            }
+        };
 
         if save_proposal.proposal_hash != answer_to_verify {
             log!("Answer don't match");
-            return RevealMinerResult::Fail;
+            return RevealMinerResult::Fail(RevealError::HashMismatch);
         }
 
-        save_proposal.answer = answer;
+        save_proposal.answer = Some(answer.clone());
         save_proposal.is_revealed = true;
 
         let reveal_miner_log = EventLog {
             standard: "emip001".to_string(),
-            version: "1.0.0".to_string(),
+            version: "2.0.0".to_string(),
             event: EventLogVariant::RevealMiner(vec![RevealMinerLog { request_id, answer, message }]),
         };
 
@@ -378,18 +676,29 @@ impl Contract {
         RevealMinerResult::Success
     }
 
-    pub fn reveal_by_validator(&mut self, request_id: String, answer: Vec<AccountId>, message: String) -> RevealValidatorResult {
+    pub fn reveal_by_validator(&mut self, request_id: String, answer: Vec<AccountId>, message: String, salt: Option<String>) -> RevealValidatorResult {
+        self.assert_not_paused();
+
         let validator = env::predecessor_account_id();
 
         if !self.is_validator_registered(validator.clone()) {
             log!("Validator is not registered: {}", validator);
-            return RevealValidatorResult::Fail;
+            return RevealValidatorResult::Fail(RevealError::NotRegistered);
+        }
+
+        if let Err(error) = self.check_salt(salt.as_ref()) {
+            return RevealValidatorResult::Fail(error);
         }
 
-        //@dev verify that the answer vector have 10 elements
-        if answer.len() != 10 {
+        let Some(ranking_size) = self.get_request(&request_id).map(|request| request.ranking_size) else {
+            log!("Request is not registered: {}", request_id);
+            return RevealValidatorResult::Fail(RevealError::RequestNotFound);
+        };
+
+        //@dev verify that the answer ranks as many miners as the ranking size of the request
+        if answer.len() != ranking_size as usize {
             log!("Invalid answer");
-            return RevealValidatorResult::Fail;
+            return RevealValidatorResult::Fail(RevealError::InvalidAnswer);
         }
 
         //@dev verify that the answer don't have repeated account
@@ -397,7 +706,7 @@ impl Contract {
         for accounts in answer.clone() {
             if !set.insert(accounts.clone()) {
                 log!("Repeated account: {}", accounts);
-                return RevealValidatorResult::Fail;
+                return RevealValidatorResult::Fail(RevealError::RepeatedAccount);
             }
         }
 
@@ -405,96 +714,84 @@ impl Contract {
         for accounts in answer.clone() {
             if !self.miners.contains_key(&accounts) {
                 log!("Account not registered as miner: {}", accounts);
-                return RevealValidatorResult::Fail;
+                return RevealValidatorResult::Fail(RevealError::MinerNotRegistered);
             }
         }
 
-        if self.get_request_by_id_mut(request_id.clone()).is_none() {
-            log!("Request is not registered: {}", request_id);
-            return RevealValidatorResult::Fail;
-        }
+        let stake = self.validators.get(&validator).copied().unwrap_or(NearToken::from_yoctonear(0));
 
-        let complete_request = self
-            .get_request_by_id_mut(request_id.clone())
-            .map_or_else(|| panic!("Request not found"), |request| request);
+        let Some(complete_request) = self.get_request_by_id_mut(request_id.clone()) else {
+            log!("Request is not registered: {}", request_id);
+            return RevealValidatorResult::Fail(RevealError::RequestNotFound);
+        };
 
-        assert_eq!(
-            Self::get_stage(complete_request.start_time),
-            RequestState::RevealValidators,
-            "Not at RevealValidators stage"
-        );
+        if Self::get_stage(complete_request) != RequestState::RevealValidators {
+            log!("Not at RevealValidators stage");
+            return RevealValidatorResult::Fail(RevealError::WrongStage);
+        }
 
-        let save_proposal = complete_request
-            .validators_proposals
-            .get_mut(&validator)
-            .map_or_else(|| panic!("proposal not found"), |proposal| proposal);
+        let Some(save_proposal) = complete_request.validators_proposals.get_mut(&validator) else {
+            log!("proposal not found");
+            return RevealValidatorResult::Fail(RevealError::ProposalNotFound);
+        };
 
         if save_proposal.is_revealed {
             log!("Proposal already revealed");
-            return RevealValidatorResult::Fail;
+            return RevealValidatorResult::Fail(RevealError::AlreadyRevealed);
         }
 
         // @dev verify that miners in the answer have a commit answer
         for accounts in answer.clone() {
             if !complete_request.miners_proposals.contains_key(&accounts) {
                 log!("Account not registered a commit: {}", accounts);
-                return RevealValidatorResult::Fail;
+                return RevealValidatorResult::Fail(RevealError::MinerNotCommitted);
             }
         }
 
         //@dev verify that the commit answer by miner was revealed
         for accounts in answer.clone() {
-            let miner_proposal = complete_request
-                .miners_proposals
-                .get(&accounts)
-                .map_or_else(|| panic!("proposal not found"), |proposal| proposal);
-
-            if !miner_proposal.is_revealed {
+            if !complete_request.miners_proposals.get(&accounts).is_some_and(|proposal| proposal.is_revealed) {
                 log!("Commit by miner not revealed: {}", accounts);
-                return RevealValidatorResult::Fail;
+                return RevealValidatorResult::Fail(RevealError::MinerNotRevealed);
             }
         }
 
-        let mut concatenated_answer: Vec<u8> = Vec::new();
-
-        concatenated_answer.extend_from_slice(request_id.as_bytes());
-        concatenated_answer.extend_from_slice(validator.as_bytes());
-
-        let value: Vec<u8> = answer.iter().flat_map(|id| id.as_bytes()).copied().collect();
-        concatenated_answer.extend_from_slice(&value);
-        concatenated_answer.extend_from_slice(message.as_bytes());
-
-        let value = env::keccak256(&concatenated_answer);
-        let hash_answer = hex::encode(value);
+        let hash_answer = salt.as_ref().map_or_else(
+            || hash_legacy_validator_answer(&request_id, validator.as_str(), &answer, &message),
+            |salt| hash_validator_commitment(&request_id, validator.as_str(), &answer, &message, salt),
+        );
 
         if save_proposal.proposal_hash != hash_answer {
             log!("Answer don't match");
             //log!("save answer: {}", save_proposal.proposal_hash);
             //log!("hash_answer calculated: {}", hash_answer);
-            return RevealValidatorResult::Fail;
+            return RevealValidatorResult::Fail(RevealError::HashMismatch);
         }
 
         save_proposal.is_revealed = true;
         let answer_for_log = answer.clone();
+        let ranking_size = answer.len();
+        let weight = complete_request.vote_weighting.weight(stake);
 
-        for addresses in answer {
+        for (position, addresses) in answer.into_iter().enumerate() {
             save_proposal.miner_addresses.push(addresses.clone());
 
-            //@dev Find the miner votes and add 1
+            //@dev Find the miner votes and add the votes of its position in the ranking, weighted by the stake of the validator
+            let votes = complete_request.scoring.votes_for_position(position, ranking_size) * weight;
             if complete_request.votes_for_miners.contains_key(&addresses) {
                 match complete_request.votes_for_miners.get(&addresses) {
-                    Some(num_votes) => complete_request.votes_for_miners.insert(addresses, *num_votes + 1),
+                    Some(num_votes) => complete_request.votes_for_miners.insert(addresses, *num_votes + votes),
                     None => panic!("miner not found"),
                 };
             } else {
-                complete_request.votes_for_miners.insert(addresses.clone(), 1);
+                complete_request.votes_for_miners.insert(addresses.clone(), votes);
                 complete_request.miner_keys.push(addresses);
             }
         }
 
         let reveal_validator_log = EventLog {
             standard: "emip001".to_string(),
-            version: "1.0.0".to_string(),
+            version: "2.0.0".to_string(),
             event: EventLogVariant::RevealValidator(vec![RevealValidatorLog {
                 request_id,
                 answer: answer_for_log,
@@ -507,13 +804,10 @@ impl Contract {
     }
 
     pub fn votes_for_miner(&mut self, request_id: String, miner_id: AccountId) {
-        if self.get_request_by_id_mut(request_id.clone()).is_none() {
+        let Some(complete_request) = self.get_request_by_id_mut(request_id.clone()) else {
             log!("Request is not registered: {}", request_id);
-        }
-
-        let complete_request = self
-            .get_request_by_id_mut(request_id)
-            .map_or_else(|| panic!("Request not found"), |request| request);
+            ContractError::RequestNotFound.panic();
+        };
 
         match complete_request.votes_for_miners.get(&miner_id) {
             Some(votes) => log!("{} have {} votes", miner_id, *votes),
@@ -521,40 +815,54 @@ impl Contract {
         };
     }
 
-    pub fn get_top_10_voters(&mut self, request_id: String) -> Vec<(AccountId, i32)> {
-        if self.get_request_by_id_mut(request_id.clone()).is_none() {
-            log!("Request is not registered: {}", request_id);
-        }
-
-        let complete_request = self
-            .get_request_by_id_mut(request_id.clone())
-            .map_or_else(|| panic!("Request not found"), |request| request);
+    // @dev Deprecated, kept for the clients of the first version. It returns the `ranking_size` top miners of the request.
+    pub fn get_top_10_voters(&mut self, request_id: String) -> Vec<(AccountId, Votes)> {
+        self.get_top_voters(request_id)
+    }
 
-        assert_eq!(Self::get_stage(complete_request.start_time), RequestState::Ended, "Not stage ended");
+    pub fn get_top_voters(&mut self, request_id: String) -> Vec<(AccountId, Votes)> {
+        let config = self.config;
 
-        let mut vote_result = Vec::new();
+        let Some(complete_request) = self.get_request_by_id_mut(request_id.clone()) else {
+            log!("Request is not registered: {}", request_id);
+            ContractError::RequestNotFound.panic();
+        };
 
-        for miner_keys in complete_request.miner_keys.iter() {
-            if let Some(votes) = complete_request.votes_for_miners.get(miner_keys) {
-                vote_result.push((miner_keys.clone(), *votes));
-            }
+        if Self::get_stage(complete_request) != RequestState::Ended {
+            ContractError::RequestNotEnded.panic();
         }
 
-        vote_result.sort_by(|a, b| b.1.cmp(&a.1));
+        let mut vote_result = Self::collect_votes(complete_request);
 
-        let top_ten: Vec<_> = vote_result.iter().take(10).cloned().collect();
-        complete_request.top_ten.clone_from(&top_ten);
+        // @dev Miners with the same votes are ranked by account id, so the order doesn't depend on when they were first voted
+        vote_result.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
 
-        let top_ten_log = EventLog {
+        let ranking_size = complete_request.ranking_size;
+        let top_miners: Vec<_> = vote_result.iter().take(ranking_size as usize).cloned().collect();
+        complete_request.top_miners.clone_from(&top_miners);
+
+        // @dev The reward pool is split only the first time the top miners are computed
+        let reward_credits = if complete_request.rewards_distributed {
+            Vec::new()
+        } else {
+            complete_request.rewards_distributed = true;
+            Self::compute_reward_credits(complete_request, &config)
+        };
+
+        let top_miners_log = EventLog {
             standard: "emip001".to_string(),
-            version: "1.0.0".to_string(),
-            event: EventLogVariant::ToptenMiners(vec![ToptenMinersLog {
-                request_id,
-                topten: top_ten.clone(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::TopMiners(vec![TopMinersLog {
+                request_id: request_id.clone(),
+                ranking_size,
+                top_miners: top_miners.clone(),
             }]),
         };
-        env::log_str(&top_ten_log.to_string());
-        top_ten
+        env::log_str(&top_miners_log.to_string());
+
+        self.distribute_rewards(request_id, reward_credits);
+
+        top_miners
     }
 }
 
@@ -565,7 +873,8 @@ mod test {
     use super::*;
     use near_sdk::{
         env,
-        test_utils::{get_logs, VMContextBuilder},
+        mock::MockAction,
+        test_utils::{get_created_receipts, get_logs, VMContextBuilder},
         testing_env, AccountId, NearToken,
     };
 
@@ -578,18 +887,25 @@ mod test {
         builder
     }
 
+    fn culture() -> CultureProfile {
+        CultureProfile {
+            document_hash: hex::encode(env::sha256(b"Governance decision")),
+            tags: vec!["governance".to_string()],
+        }
+    }
+
     #[test]
     fn test_request_governance_decision() {
-        let mut contract = Contract::new();
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
 
         let context = get_context("account1.near".parse().unwrap(), 100000000, NearToken::from_near(5));
         testing_env!(context.build());
 
         let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-        contract.register_protocol("Governance decision".to_string(), modules);
+        contract.register_protocol(culture(), modules, None);
 
         let message = "Should we add this new NFT to our protocol?";
-        let result_1 = contract.request_governance_decision(message.to_string());
+        let result_1 = contract.request_governance_decision(message.to_string(), None, None, None, None, None);
         assert_eq!(result_1, RegisterRequestResult::Success);
 
         let sender_account = env::predecessor_account_id();
@@ -603,27 +919,27 @@ mod test {
         assert_eq!(logs.len(), 2);
         assert_eq!(
             logs[0],
-            r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_protocol","data":[{"account":"account1.near"}]}"#
+            r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"register_protocol","data":[{"account":"account1.near"}]}"#
         );
 
         assert_eq!(
             logs[1],
-            r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_request","data":[{"request_id":"73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b"}]}"#
+            r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"register_request","data":[{"request_id":"73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b","culture":{"document_hash":"d5c53010c394ebd738ac31123aa35bd43ccbaf783b6a27c6df7aa3e0bddd003a","tags":["governance"]},"protocol_version":1}]}"#
         );
     }
 
     #[test]
     fn test_multiple_request_governance_decision() {
-        let mut contract = Contract::new();
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
 
         let context = get_context("account1.near".parse().unwrap(), 100000000, NearToken::from_near(5));
         testing_env!(context.build());
 
         let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-        contract.register_protocol("Governance decision".to_string(), modules);
+        contract.register_protocol(culture(), modules, None);
 
         let message = "Should we add this new NFT to our protocol?";
-        let result_1 = contract.request_governance_decision(message.to_string());
+        let result_1 = contract.request_governance_decision(message.to_string(), None, None, None, None, None);
         assert_eq!(result_1, RegisterRequestResult::Success);
 
         let sender_account = env::predecessor_account_id();
@@ -637,22 +953,22 @@ mod test {
         assert_eq!(logs.len(), 2);
         assert_eq!(
             logs[0],
-            r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_protocol","data":[{"account":"account1.near"}]}"#
+            r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"register_protocol","data":[{"account":"account1.near"}]}"#
         );
 
         assert_eq!(
             logs[1],
-            r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_request","data":[{"request_id":"73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b"}]}"#
+            r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"register_request","data":[{"request_id":"73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b","culture":{"document_hash":"d5c53010c394ebd738ac31123aa35bd43ccbaf783b6a27c6df7aa3e0bddd003a","tags":["governance"]},"protocol_version":1}]}"#
         );
 
         let context = get_context("account2.near".parse().unwrap(), 100000000, NearToken::from_yoctonear(10u128.pow(25)));
         testing_env!(context.build());
 
         let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-        contract.register_protocol("Governance decision for ethereum".to_string(), modules);
+        contract.register_protocol(culture(), modules, None);
 
         let message_2 = "Should we add this to our protocol?";
-        let result_2 = contract.request_governance_decision(message_2.to_string());
+        let result_2 = contract.request_governance_decision(message_2.to_string(), None, None, None, None, None);
         assert_eq!(result_2, RegisterRequestResult::Success);
 
         let sender_account_2 = env::predecessor_account_id();
@@ -665,39 +981,39 @@ mod test {
         assert_eq!(logs.len(), 2);
         assert_eq!(
             logs[0],
-            r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_protocol","data":[{"account":"account2.near"}]}"#
+            r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"register_protocol","data":[{"account":"account2.near"}]}"#
         );
         assert_eq!(
             logs[1],
-            r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_request","data":[{"request_id":"c4b35bc95d323446f6f800e7639457cddc34c7f768772e4871adf2dd34f89ed8"}]}"#
+            r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"register_request","data":[{"request_id":"c4b35bc95d323446f6f800e7639457cddc34c7f768772e4871adf2dd34f89ed8","culture":{"document_hash":"d5c53010c394ebd738ac31123aa35bd43ccbaf783b6a27c6df7aa3e0bddd003a","tags":["governance"]},"protocol_version":1}]}"#
         );
     }
 
     #[test]
     #[should_panic(expected = "Account unregistered: account1.near")]
     fn test_request_governance_decision_with_an_unregistered_protocol() {
-        let mut contract = Contract::new();
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
 
         let context = get_context("account1.near".parse().unwrap(), 100000000, NearToken::from_yoctonear(10u128.pow(2)));
         testing_env!(context.build());
 
         let message = "Should we add this new NFT to our protocol?";
 
-        contract.request_governance_decision(message.to_string());
+        contract.request_governance_decision(message.to_string(), None, None, None, None, None);
     }
 
     #[test]
     fn test_get_request_by_id_mut() {
-        let mut contract = Contract::new();
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
 
         let context = get_context("account1.near".parse().unwrap(), 100000000, NearToken::from_near(5));
         testing_env!(context.build());
 
         let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-        contract.register_protocol("Governance decision for ethereum".to_string(), modules);
+        contract.register_protocol(culture(), modules, None);
 
         let message = "Should we add this new NFT to our protocol?";
-        contract.request_governance_decision(message.to_string());
+        contract.request_governance_decision(message.to_string(), None, None, None, None, None);
 
         let request_id = "73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b";
         assert!(contract.get_request_by_id_mut(request_id.to_string()).is_some());
@@ -709,20 +1025,376 @@ mod test {
         let context = get_context("account1.near".parse().unwrap(), 100000000, NearToken::from_yoctonear(10u128.pow(24)));
         testing_env!(context.build());
 
-        let mut contract = Contract::new();
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
 
         let message = "Should we add this new NFT to our protocol?";
-        contract.request_governance_decision(message.to_string());
+        contract.request_governance_decision(message.to_string(), None, None, None, None, None);
 
         let request_id = "73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b";
         assert!(contract.get_request_by_id_mut(request_id.to_string()).is_some());
     }
 
+    // @dev Writes a request of the first deployed version, stored without a version tag
+    fn insert_legacy_request(request_id: &str) {
+        let miner: AccountId = "miner1.near".parse().unwrap();
+
+        let mut requests: LookupMap<Hash, migration::RequestV0> = LookupMap::new(REQUESTS_PREFIX);
+        let mut request = migration::RequestV0 {
+            sender: "account1.near".parse().unwrap(),
+            request_id: request_id.to_string(),
+            start_time: 100000000,
+            miners_proposals: LookupMap::new(LEGACY_MINER_PROPOSALS_PREFIX),
+            validators_proposals: LookupMap::new(LEGACY_VALIDATOR_PROPOSALS_PREFIX),
+            votes_for_miners: LookupMap::new(LEGACY_VOTES_FOR_MINERS_PREFIX),
+            miner_keys: vec![miner.clone()],
+            top_ten: vec![(miner.clone(), 1)],
+        };
+
+        request.miners_proposals.insert(
+            miner.clone(),
+            migration::MinerProposalV0 {
+                proposal_hash: "hash".to_string(),
+                answer: true,
+                is_revealed: true,
+            },
+        );
+        request.votes_for_miners.insert(miner, 1);
+        request.miners_proposals.flush();
+        request.votes_for_miners.flush();
+
+        requests.insert(request_id.to_string(), request);
+        requests.flush();
+    }
+
+    // @dev Writes a protocol of the first deployed version, stored without a version tag
+    fn insert_legacy_protocol(account: &str) {
+        let mut protocols: LookupMap<AccountId, migration::ProtocolV0> = LookupMap::new(PROTOCOLS_PREFIX);
+        protocols.insert(
+            account.parse().unwrap(),
+            migration::ProtocolV0 {
+                account: account.parse().unwrap(),
+                culture: "Governance decision".to_string(),
+                modules: vec![Module::TextPrompting],
+                registration_fee: NearToken::from_near(5),
+            },
+        );
+        protocols.flush();
+    }
+
+    #[test]
+    #[should_panic(expected = "Protocol storage not migrated: account1.near")]
+    fn test_request_governance_decision_when_the_protocol_is_not_migrated() {
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
+
+        let context = get_context("account1.near".parse().unwrap(), 100000000, NearToken::from_near(5));
+        testing_env!(context.build());
+
+        insert_legacy_protocol("account1.near");
+
+        contract.request_governance_decision("Should we add this new NFT to our protocol?".to_string(), None, None, None, None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Protocol storage not migrated: account1.near")]
+    fn test_update_protocol_when_the_protocol_is_not_migrated() {
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
+
+        let context = get_context("account1.near".parse().unwrap(), 100000000, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        insert_legacy_protocol("account1.near");
+
+        contract.update_protocol(culture());
+    }
+
+    #[test]
+    fn test_protocol_views_when_the_protocol_is_not_migrated() {
+        let contract = Contract::new("owner.near".parse().unwrap(), Config::default());
+
+        insert_legacy_protocol("account1.near");
+
+        let protocol: AccountId = "account1.near".parse().unwrap();
+        assert!(contract.is_protocol_registered(protocol.clone()));
+        assert_eq!(contract.get_protocol_culture(protocol.clone()), None);
+        assert_eq!(contract.get_protocol_version(protocol.clone()), None);
+        assert!(contract.get_protocol_modules(protocol).is_empty());
+    }
+
+    #[test]
+    fn test_request_governance_decision_when_a_legacy_request_is_not_indexed() {
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
+
+        let context = get_context("account1.near".parse().unwrap(), 100000000, NearToken::from_near(5));
+        testing_env!(context.build());
+
+        contract.register_protocol(culture(), vec![Module::TextPrompting], None);
+
+        // @dev Requests created before the index keep the id of the first version until `migrate_request_storage` indexes them
+        let request_id = "73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b";
+        insert_legacy_request(request_id);
+
+        let message = "Should we add this new NFT to our protocol?";
+        let result = contract.request_governance_decision(message.to_string(), None, None, None, None, None);
+
+        assert_eq!(result, RegisterRequestResult::AlreadyRegistered);
+        assert_eq!(contract.get_request_nonce("account1.near".parse().unwrap()), 0);
+
+        // @dev The reward pool of the rejected request is refunded
+        let refund = get_created_receipts().pop().unwrap();
+        assert_eq!(refund.receiver_id, "account1.near".parse::<AccountId>().unwrap());
+        assert!(matches!(refund.actions[..], [MockAction::Transfer { deposit, .. }] if deposit == NearToken::from_near(5)));
+        assert_eq!(
+            get_logs().last().unwrap(),
+            &format!("Attempted to register an already registered request: {}", request_id)
+        );
+    }
+
+    // @dev Requests of the first version used the default phase durations
+    const LEGACY_REQUEST_END: u64 = 100000000 + COMMIT_MINER_DURATION + REVEAL_MINER_DURATION + COMMIT_VALIDATOR_DURATION + REVEAL_VALIDATOR_DURATION;
+
+    #[test]
+    fn test_migrate_request_storage() {
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
+
+        let context = get_context("owner.near".parse().unwrap(), LEGACY_REQUEST_END, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        let request_id = "73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b";
+        insert_legacy_request(request_id);
+
+        let migrated = contract.migrate_request_storage(vec![request_id.to_string()]);
+        assert_eq!(migrated, 1);
+
+        let miner: AccountId = "miner1.near".parse().unwrap();
+
+        let request = contract.get_request_by_id_mut(request_id.to_string()).unwrap();
+        assert_eq!(request.answer_kind, AnswerKind::YesNo);
+        assert_eq!(request.ranking_size, DEFAULT_RANKING_SIZE);
+        assert_eq!(request.scoring, ScoringMode::Approval);
+        assert_eq!(request.vote_weighting, VoteWeighting::Equal);
+        assert_eq!(request.protocol_version, 0);
+        assert_eq!(request.miners_proposals.get(&miner).unwrap().answer, Some(Answer::YesNo(true)));
+        assert!(request.miners_proposals.get(&miner).unwrap().is_revealed);
+        assert_eq!(request.votes_for_miners.get(&miner), Some(&1));
+        assert_eq!(request.top_miners, vec![(miner, 1)]);
+
+        let migrated = contract.migrate_request_storage(vec![request_id.to_string()]);
+        assert_eq!(migrated, 0);
+        assert_eq!(get_logs().last().unwrap(), &format!("Request storage already migrated: {}", request_id));
+    }
+
+    #[test]
+    fn test_migrate_request_storage_before_the_request_ended() {
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
+
+        let context = get_context("owner.near".parse().unwrap(), LEGACY_REQUEST_END - 1, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        let request_id = "73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b";
+        insert_legacy_request(request_id);
+
+        assert_eq!(contract.migrate_request_storage(vec![request_id.to_string()]), 0);
+        assert_eq!(get_logs().last().unwrap(), &format!("Request has not ended: {}", request_id));
+        assert_eq!(contract.get_request_nonce("account1.near".parse().unwrap()), 0);
+
+        let context = get_context("owner.near".parse().unwrap(), LEGACY_REQUEST_END, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        assert_eq!(contract.migrate_request_storage(vec![request_id.to_string()]), 1);
+    }
+
+    #[test]
+    fn test_migrate_request_storage_skips_new_requests() {
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
+
+        let context = get_context("alice.near".parse().unwrap(), 100000000, NearToken::from_near(5));
+        testing_env!(context.build());
+
+        let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+        contract.register_protocol(culture(), modules, None);
+        contract.request_governance_decision("Should we add this new NFT to our protocol?".to_string(), None, None, None, None, None);
+
+        let context = get_context("owner.near".parse().unwrap(), 100000000, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        let request_id = hex::encode(env::keccak256(b"alice.nearShould we add this new NFT to our protocol?"));
+        let migrated = contract.migrate_request_storage(vec![request_id, "unknown".to_string()]);
+
+        assert_eq!(migrated, 0);
+        assert_eq!(get_logs().last().unwrap(), "Request is not registered: unknown");
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the owner can call this method")]
+    fn test_migrate_request_storage_when_caller_is_not_the_owner() {
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
+
+        let context = get_context("account1.near".parse().unwrap(), 100000000, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        contract.migrate_request_storage(vec!["unknown".to_string()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the owner can call this method")]
+    fn test_migrate_protocols_when_caller_is_not_the_owner() {
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
+
+        let context = get_context("account1.near".parse().unwrap(), 100000000, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        contract.migrate_protocols(vec!["account1.near".parse().unwrap()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the owner can call this method")]
+    fn test_migrate_accounts_when_caller_is_not_the_owner() {
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
+
+        let context = get_context("account1.near".parse().unwrap(), 100000000, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        contract.migrate_accounts(Participant::Miner, vec!["miner1.near".parse().unwrap()]);
+    }
+
     #[test]
     fn test_get_request_by_id_mut_when_not_registered() {
-        let mut contract = Contract::new();
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
         let request_id = "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae727";
 
         assert!(contract.get_request_by_id_mut(request_id.to_string()).is_none());
     }
+
+    // @dev Writes the state of the first deployed version: one protocol, one miner and one request with a revealed proposal
+    fn write_v0_snapshot(request_id: &str) {
+        let protocol: AccountId = "account1.near".parse().unwrap();
+        let miner: AccountId = "miner1.near".parse().unwrap();
+
+        let mut old = migration::ContractV0 {
+            protocols: LookupMap::new(PROTOCOLS_PREFIX),
+            requests: LookupMap::new(REQUESTS_PREFIX),
+            miners: LookupMap::new(b"miners".to_vec()),
+            validators: LookupMap::new(b"validators".to_vec()),
+        };
+
+        old.protocols.insert(
+            protocol.clone(),
+            migration::ProtocolV0 {
+                account: protocol.clone(),
+                culture: "Governance decision".to_string(),
+                modules: vec![Module::TextPrompting],
+                registration_fee: NearToken::from_near(5),
+            },
+        );
+        old.miners.insert(miner.clone(), NearToken::from_near(1));
+
+        let mut request = migration::RequestV0 {
+            sender: protocol,
+            request_id: request_id.to_string(),
+            start_time: 100000000,
+            miners_proposals: LookupMap::new(LEGACY_MINER_PROPOSALS_PREFIX),
+            validators_proposals: LookupMap::new(LEGACY_VALIDATOR_PROPOSALS_PREFIX),
+            votes_for_miners: LookupMap::new(LEGACY_VOTES_FOR_MINERS_PREFIX),
+            miner_keys: vec![miner.clone()],
+            top_ten: Vec::new(),
+        };
+        request.miners_proposals.insert(
+            miner.clone(),
+            migration::MinerProposalV0 {
+                proposal_hash: "hash".to_string(),
+                answer: true,
+                is_revealed: true,
+            },
+        );
+        request.votes_for_miners.insert(miner, 1);
+        request.miners_proposals.flush();
+        request.votes_for_miners.flush();
+
+        old.requests.insert(request_id.to_string(), request);
+        old.protocols.flush();
+        old.requests.flush();
+        old.miners.flush();
+
+        env::state_write(&old);
+    }
+
+    #[test]
+    fn test_migrate_from_v0_snapshot() {
+        let context = get_context("alice.near".parse().unwrap(), 100000000, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        let request_id = "73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b";
+        write_v0_snapshot(request_id);
+
+        let mut contract = Contract::migrate();
+
+        let protocol: AccountId = "account1.near".parse().unwrap();
+        let miner: AccountId = "miner1.near".parse().unwrap();
+
+        assert_eq!(contract.get_owner(), "alice.near".parse::<AccountId>().unwrap());
+        assert!(contract.is_miner_registered(miner.clone()));
+        assert!(contract.get_miners(None, None).is_empty());
+
+        assert_eq!(contract.migrate_accounts(Participant::Miner, vec![miner.clone()]), 1);
+        assert_eq!(contract.migrate_accounts(Participant::Miner, vec![miner.clone()]), 0);
+        assert_eq!(get_logs().last().unwrap(), "Account already migrated: miner1.near");
+        assert_eq!(contract.migrate_accounts(Participant::Validator, vec![miner.clone()]), 0);
+        assert_eq!(get_logs().last().unwrap(), "Account not registered: miner1.near");
+        assert_eq!(contract.get_miners(None, None), vec![miner.clone()]);
+
+        assert_eq!(contract.migrate_protocols(vec![protocol.clone()]), 1);
+        assert_eq!(contract.migrate_protocols(vec![protocol.clone()]), 0);
+        assert_eq!(get_logs().last().unwrap(), "Protocol already migrated or not registered: account1.near");
+        assert!(contract.is_protocol_registered(protocol.clone()));
+        assert_eq!(contract.get_protocols(None, None), vec![protocol.clone()]);
+        assert_eq!(contract.get_protocol_culture(protocol.clone()), None);
+        assert_eq!(contract.get_protocol_version(protocol.clone()), Some(1));
+
+        let context = get_context("alice.near".parse().unwrap(), LEGACY_REQUEST_END, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        assert_eq!(contract.migrate_request_storage(vec![request_id.to_string()]), 1);
+        assert_eq!(contract.migrate_request_storage(vec![request_id.to_string()]), 0);
+
+        let request = contract.get_request_by_id_mut(request_id.to_string()).unwrap();
+        assert_eq!(request.timing, PhaseDurations::default());
+
+        assert!(contract.get_miner_proposal(request_id.to_string(), miner.clone()).unwrap().is_revealed);
+        assert_eq!(contract.get_votes_for_miner(request_id.to_string(), miner), Some(1));
+        assert_eq!(contract.get_requests_by_protocol(protocol, None, None), vec![request_id.to_string()]);
+    }
+
+    #[test]
+    fn test_migrate_keeps_current_state() {
+        let context = get_context("alice.near".parse().unwrap(), 100000000, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        env::state_write(&Contract::new("owner.near".parse().unwrap(), Config::default()));
+
+        let contract = Contract::migrate();
+
+        assert_eq!(contract.get_owner(), "owner.near".parse::<AccountId>().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract state has an unknown layout")]
+    fn test_migrate_from_an_unknown_layout() {
+        let context = get_context("alice.near".parse().unwrap(), 100000000, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        env::storage_write(b"STATE", &[7, 1, 2]);
+
+        Contract::migrate();
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the owner can call this method")]
+    fn test_upgrade_when_caller_is_not_the_owner() {
+        let mut contract = Contract::new("owner.near".parse().unwrap(), Config::default());
+
+        let context = get_context("account1.near".parse().unwrap(), 100000000, NearToken::from_near(0));
+        testing_env!(context.build());
+
+        contract.upgrade(vec![0, 97, 115, 109].into());
+    }
 }
@@ -0,0 +1,271 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::Base64VecU8;
+use near_sdk::store::LookupMap;
+use near_sdk::{env, log, near_bindgen, AccountId, FunctionError, NearToken, Promise};
+
+use crate::{
+    Answer, AnswerKind, Config, Contract, ContractError, ContractExt, Hash, MinerProposal, Module, Participant, PhaseDurations, Protocol, Request,
+    RequestState, ScoringMode, Stake, StateVersion, StorageKey, ValidatorProposal, VersionedProtocol, VersionedRequest, VoteWeighting, Votes,
+    DEFAULT_RANKING_SIZE, GAS_FOR_MIGRATE, PROTOCOLS_PREFIX, REQUESTS_PREFIX,
+};
+
+// @dev Layouts of the first deployed version, stored without a version tag
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ContractV0 {
+    pub protocols: LookupMap<AccountId, ProtocolV0>,
+    pub requests: LookupMap<Hash, RequestV0>,
+    pub miners: LookupMap<AccountId, Stake>,
+    pub validators: LookupMap<AccountId, Stake>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ProtocolV0 {
+    pub account: AccountId,
+    pub culture: String,
+    pub modules: Vec<Module>,
+    pub registration_fee: NearToken,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct RequestV0 {
+    pub sender: AccountId,
+    pub request_id: String,
+    pub start_time: u64,
+    pub miners_proposals: LookupMap<AccountId, MinerProposalV0>,
+    pub validators_proposals: LookupMap<AccountId, ValidatorProposal>,
+    pub votes_for_miners: LookupMap<AccountId, i32>,
+    pub miner_keys: Vec<AccountId>,
+    pub top_ten: Vec<(AccountId, i32)>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct MinerProposalV0 {
+    pub proposal_hash: Hash,
+    pub answer: bool,
+    pub is_revealed: bool,
+}
+
+// @dev A free text culture is not a document hash, protocols set their profile with `update_protocol`
+impl From<ProtocolV0> for Protocol {
+    fn from(protocol: ProtocolV0) -> Self {
+        Self {
+            account: protocol.account,
+            culture: None,
+            modules: protocol.modules,
+            registration_fee: protocol.registration_fee,
+            timing: PhaseDurations::default(),
+            version: 1,
+        }
+    }
+}
+
+// @dev Unrevealed proposals stored `false` as a placeholder answer
+impl From<MinerProposalV0> for MinerProposal {
+    fn from(proposal: MinerProposalV0) -> Self {
+        Self {
+            proposal_hash: proposal.proposal_hash,
+            answer: proposal.is_revealed.then_some(Answer::YesNo(proposal.answer)),
+            is_revealed: proposal.is_revealed,
+        }
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    // @dev Called by `upgrade` once the new code is deployed. The root state starts with its version, the first deployed version
+    // is the only untagged layout: it starts with the length of the prefix of its protocols, which is never a valid tag.
+    // State of the first version gets the contract account as owner, which can hand it over with `transfer_ownership`.
+    // Its protocols and requests are rewritten in batches afterwards.
+    #[allow(clippy::use_self)]
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let state = env::storage_read(b"STATE").unwrap_or_else(|| ContractError::UnknownStateLayout.panic());
+        let version = state.first().and_then(|tag| StateVersion::try_from_slice(&[*tag]).ok());
+
+        let contract = match version {
+            Some(StateVersion::V1) => Self::try_from_slice(&state).ok(),
+            None => ContractV0::try_from_slice(&state).ok().map(Self::from),
+        };
+
+        contract.unwrap_or_else(|| ContractError::UnknownStateLayout.panic())
+    }
+
+    // @dev Deploys the new code on the contract account and migrates the state in the same receipt,
+    // if the migration fails the code deployment is reverted as well.
+    pub fn upgrade(&mut self, code: Base64VecU8) -> Promise {
+        self.assert_owner();
+
+        Promise::new(env::current_account_id()).deploy_contract(code.into()).function_call(
+            "migrate".to_string(),
+            Vec::new(),
+            NearToken::from_yoctonear(0),
+            GAS_FOR_MIGRATE,
+        )
+    }
+
+    // @dev Rewrites the protocols of the first version, they were stored without a version tag
+    pub fn migrate_protocols(&mut self, accounts: Vec<AccountId>) -> u32 {
+        self.assert_owner();
+
+        let mut migrated = 0;
+
+        for account in accounts {
+            let Some(protocol) = Self::read_unversioned::<_, VersionedProtocol, ProtocolV0>(PROTOCOLS_PREFIX, &account) else {
+                log!("Protocol already migrated or not registered: {}", account);
+                continue;
+            };
+
+            // @dev `set` doesn't read the untagged value back, flushing makes later raw reads see the new layout
+            self.protocols.set(account.clone(), Some(Protocol::from(protocol).into()));
+            self.protocols.flush();
+            self.protocol_index.insert(account);
+            migrated += 1;
+        }
+
+        migrated
+    }
+
+    // @dev Lists the miners or validators of the first version, their stakes are kept by `migrate` but they were never indexed.
+    // Miners declare their modules afterwards with `update_miner_modules`.
+    pub fn migrate_accounts(&mut self, participant: Participant, accounts: Vec<AccountId>) -> u32 {
+        self.assert_owner();
+
+        let mut migrated = 0;
+
+        for account in accounts {
+            if !self.stakes(participant).contains_key(&account) {
+                log!("Account not registered: {}", account);
+                continue;
+            }
+
+            if !self.index_mut(participant).insert(account.clone()) {
+                log!("Account already migrated: {}", account);
+                continue;
+            }
+
+            migrated += 1;
+        }
+
+        migrated
+    }
+
+    // @dev Rewrites the requests of the first version, they were stored without a version tag. Their collections were created
+    // with the shared prefixes and are moved to the prefixes of the request. Only the miners validators voted for, the top miners among them,
+    // keep their answers and votes: the other miner proposals, the validator proposals and the commits are dropped.
+    // Requests are only migrated once they have ended, so no miner or validator still has to reveal on them.
+    // The legacy keys are left in place because other requests may still need them.
+    pub fn migrate_request_storage(&mut self, request_ids: Vec<Hash>) -> u32 {
+        self.assert_owner();
+
+        let mut migrated = 0;
+
+        for request_id in request_ids {
+            let Some(request) = Self::read_unversioned::<_, VersionedRequest, RequestV0>(REQUESTS_PREFIX, &request_id) else {
+                if self.requests.contains_key(&request_id) {
+                    log!("Request storage already migrated: {}", request_id);
+                } else {
+                    log!("Request is not registered: {}", request_id);
+                }
+                continue;
+            };
+
+            if Self::stage_at(request.start_time, PhaseDurations::default()) != RequestState::Ended {
+                log!("Request has not ended: {}", request_id);
+                continue;
+            }
+
+            let sender = request.sender.clone();
+            self.requests.set(request_id.clone(), Some(Self::migrate_request(request).into()));
+            self.requests.flush();
+            self.index_request(&sender, request_id);
+            migrated += 1;
+        }
+
+        migrated
+    }
+}
+
+impl Contract {
+    // @dev Protocols of the first version can't be deserialized as `VersionedProtocol`, so they are detected with a raw read
+    pub(crate) fn is_unmigrated_protocol(account: &AccountId) -> bool {
+        Self::read_unversioned::<_, VersionedProtocol, ProtocolV0>(PROTOCOLS_PREFIX, account).is_some()
+    }
+
+    // @dev Reads an entry straight from storage, only if it was written without a version tag
+    fn read_unversioned<K: BorshSerialize, V: BorshDeserialize, T: BorshDeserialize>(prefix: &[u8], key: &K) -> Option<T> {
+        let mut storage_key = prefix.to_vec();
+        key.serialize(&mut storage_key).ok()?;
+
+        let value = env::storage_read(&storage_key)?;
+        if V::try_from_slice(&value).is_ok() {
+            return None;
+        }
+
+        T::try_from_slice(&value).ok()
+    }
+
+    // @dev The first version didn't track commits nor take a request fee, so there is nothing to slash or reward.
+    // Its requests were yes or no questions ranking ten miners, every ranked miner got one vote.
+    fn migrate_request(request: RequestV0) -> Request {
+        let request_id = request.request_id;
+
+        let mut miners_proposals = LookupMap::new(StorageKey::MinerProposals {
+            request_id: request_id.clone(),
+        });
+        let mut votes_for_miners = LookupMap::new(StorageKey::VotesForMiners {
+            request_id: request_id.clone(),
+        });
+        for miner in &request.miner_keys {
+            if let Some(proposal) = request.miners_proposals.get(miner) {
+                miners_proposals.insert(miner.clone(), MinerProposal::from(proposal.clone()));
+            }
+            if let Some(votes) = request.votes_for_miners.get(miner) {
+                votes_for_miners.insert(miner.clone(), Votes::try_from(*votes).unwrap_or_default());
+            }
+        }
+        miners_proposals.flush();
+        votes_for_miners.flush();
+
+        Request {
+            sender: request.sender,
+            request_id: request_id.clone(),
+            start_time: request.start_time,
+            timing: PhaseDurations::default(),
+            answer_kind: AnswerKind::YesNo,
+            ranking_size: DEFAULT_RANKING_SIZE,
+            scoring: ScoringMode::Approval,
+            vote_weighting: VoteWeighting::Equal,
+            committee: None,
+            module: None,
+            culture: None,
+            protocol_version: 0,
+            miners_proposals,
+            validators_proposals: LookupMap::new(StorageKey::ValidatorProposals { request_id }),
+            votes_for_miners,
+            miner_keys: request.miner_keys,
+            top_miners: request
+                .top_ten
+                .into_iter()
+                .map(|(miner, votes)| (miner, Votes::try_from(votes).unwrap_or_default()))
+                .collect(),
+            committed_miners: Vec::new(),
+            committed_validators: Vec::new(),
+            is_settled: false,
+            reward_pool: NearToken::from_yoctonear(0),
+            rewards_distributed: true,
+            result: None,
+            delivery: None,
+        }
+    }
+}
+
+// @dev Only the stakes of the miners and validators are kept, accounts, protocols and requests are migrated in batches
+impl From<ContractV0> for Contract {
+    fn from(old: ContractV0) -> Self {
+        let mut contract = Self::new(env::current_account_id(), Config::default());
+        contract.miners = old.miners;
+        contract.validators = old.validators;
+        contract
+    }
+}
@@ -1,10 +1,19 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::store::LookupMap;
-use near_sdk::{AccountId, NearToken};
+use near_sdk::{AccountId, BorshStorageKey, NearToken};
+
+use crate::{
+    AnswerRef, CommitError, RevealError, BASIS_POINTS, COMMIT_MINER_DURATION, COMMIT_VALIDATOR_DURATION, CONTENT_HASH_LENGTH, DEFAULT_RANKING_SIZE,
+    DEREGISTRATION_REFUND_BASIS_POINTS, MAX_ANSWER_OPTIONS, MAX_COMMITTEE_SIZE, MAX_CONFIG_AMOUNT, MAX_CULTURE_TAGS, MAX_CULTURE_TAG_LENGTH,
+    MAX_PHASE_DURATION, MAX_POSITION_WEIGHT, MAX_RANKING_SIZE, MAX_UNBONDING_PERIOD, MINER_REWARD_BASIS_POINTS, MIN_COMMITTEE_SIZE, MIN_CONFIG_AMOUNT,
+    MIN_MINER_STAKE, MIN_PHASE_DURATION, MIN_RANKING_SIZE, MIN_REQUEST_FEE, MIN_UNBONDING_PERIOD, MIN_VALIDATOR_STAKE, PROTOCOL_REGISTRATION_FEE,
+    REVEAL_MINER_DURATION, REVEAL_VALIDATOR_DURATION, SLASH_BASIS_POINTS, UNBONDING_PERIOD,
+};
 
 pub type Hash = String;
 pub type Stake = NearToken;
+pub type Votes = u64;
 
 #[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
 #[serde(crate = "near_sdk::serde")]
@@ -40,35 +49,245 @@ pub enum RegisterRequestResult {
 #[serde(crate = "near_sdk::serde")]
 pub enum CommitMinerResult {
     Success,
-    Fail,
+    Fail(CommitError),
 }
 
 #[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
 #[serde(crate = "near_sdk::serde")]
 pub enum RevealMinerResult {
     Success,
-    Fail,
+    Fail(RevealError),
 }
 
 #[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
 #[serde(crate = "near_sdk::serde")]
 pub enum CommitValidatorResult {
     Success,
-    Fail,
+    Fail(CommitError),
 }
 
 #[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
 #[serde(crate = "near_sdk::serde")]
 pub enum RevealValidatorResult {
     Success,
-    Fail,
+    Fail(RevealError),
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum RequestUnstakeResult {
+    Success,
+    NotRegistered,
+    AlreadyRequested,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum WithdrawStakeResult {
+    Success,
+    NotRequested,
+    StillUnbonding,
+    OpenCommits,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum UpdateMinerModulesResult {
+    Success,
+    NotRegistered,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum UpdateProtocolResult {
+    Success,
+    NotRegistered,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AddModuleResult {
+    Success,
+    NotRegistered,
+    AlreadySupported,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum RemoveModuleResult {
+    Success,
+    NotRegistered,
+    NotSupported,
+    LastModule,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum DeregisterProtocolResult {
+    Success,
+    NotRegistered,
+    UnfinishedRequests,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AddStakeResult {
+    Success,
+    NotRegistered,
+    Unbonding,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum DecreaseStakeResult {
+    Success,
+    NotRegistered,
+    Unbonding,
+    BelowMinimum,
+    OpenCommits,
 }
 
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum SettleRequestResult {
+    Success,
+    RequestNotFound,
+    NotEnded,
+    AlreadySettled,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ClaimRewardsResult {
+    Success,
+    NothingToClaim,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum FinalizeRequestResult {
+    Success,
+    RequestNotFound,
+    NotEnded,
+    AlreadyFinalized,
+}
+
+// @dev Answer to the governance question, computed from the answers of the top miners.
+// `answers` counts the revealed answers of the top miners and `support` the ones equal to the decision.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DecisionResult {
+    pub decision: Option<Answer>,
+    pub answers: u32,
+    pub support: u32,
+    pub revealed_miners: u32,
+    pub committed_miners: u32,
+    pub revealed_validators: u32,
+    pub committed_validators: u32,
+}
+
+// @dev Type of answer the miners give to a request, declared by the protocol when it creates the request
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AnswerKind {
+    // @dev Decided by the majority, a tie is no and no answers is no decision
+    #[default]
+    YesNo,
+    // @dev Index of one of the options, decided by plurality
+    MultipleChoice {
+        options: u32,
+    },
+    // @dev Value within the bounds, decided by the median
+    Score {
+        min: i64,
+        max: i64,
+    },
+    // @dev Hash of a free-form answer stored off-chain, decided by plurality
+    Content,
+}
+
+impl AnswerKind {
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            Self::MultipleChoice { options } => (2..=MAX_ANSWER_OPTIONS).contains(&options),
+            Self::Score { min, max } => min < max,
+            Self::YesNo | Self::Content => true,
+        }
+    }
+
+    pub fn accepts(&self, answer: &Answer) -> bool {
+        match (*self, answer) {
+            (Self::YesNo, Answer::YesNo(_)) => true,
+            (Self::MultipleChoice { options }, Answer::Choice(option)) => *option < options,
+            (Self::Score { min, max }, Answer::Score(score)) => (min..=max).contains(score),
+            (Self::Content, Answer::Content(content_hash)) => {
+                content_hash.len() == CONTENT_HASH_LENGTH && content_hash.bytes().all(|byte| byte.is_ascii_hexdigit())
+            }
+            _ => false,
+        }
+    }
+}
+
+// @dev How the ranking revealed by a validator turns into votes for the miners, chosen by the protocol when it creates the request
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ScoringMode {
+    // @dev One vote for every ranked miner, the position doesn't matter
+    #[default]
+    Approval,
+    // @dev The first of n ranked miners gets n votes, the second n - 1 and the last one 1
+    Borda,
+    // @dev Votes given to each position of the ranking, one weight per position
+    Weighted {
+        weights: Vec<u32>,
+    },
+}
+
+impl ScoringMode {
+    pub fn is_valid_for(&self, ranking_size: u32) -> bool {
+        match self {
+            Self::Weighted { weights } => weights.len() == ranking_size as usize && weights.iter().all(|weight| *weight <= MAX_POSITION_WEIGHT),
+            Self::Approval | Self::Borda => true,
+        }
+    }
+
+    // @dev Rankings always have the ranking size of the request, validated before the votes are counted
+    pub fn votes_for_position(&self, position: usize, ranking_size: usize) -> Votes {
+        match self {
+            Self::Approval => 1,
+            Self::Borda => ranking_size.saturating_sub(position) as Votes,
+            Self::Weighted { weights } => weights.get(position).map_or(0, |weight| Votes::from(*weight)),
+        }
+    }
+}
+
+// @dev Answers are ordered so plurality ties and the median are deterministic
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Answer {
+    YesNo(bool),
+    Choice(u32),
+    Score(i64),
+    Content(Hash),
+}
+
+impl<'a> From<&'a Answer> for AnswerRef<'a> {
+    fn from(answer: &'a Answer) -> Self {
+        match answer {
+            Answer::YesNo(answer) => AnswerRef::YesNo(*answer),
+            Answer::Choice(option) => AnswerRef::Choice(*option),
+            Answer::Score(score) => AnswerRef::Score(*score),
+            Answer::Content(content_hash) => AnswerRef::Content(content_hash),
+        }
+    }
+}
+
+// @dev The answer is only known once the proposal is revealed
 #[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct MinerProposal {
     pub proposal_hash: Hash,
-    pub answer: bool,
+    pub answer: Option<Answer>,
     pub is_revealed: bool,
 }
 
@@ -83,13 +302,44 @@ pub enum RequestState {
     Ended,
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Participant {
+    Miner,
+    Validator,
+}
+
+// @dev Part of a stake that was decreased and is waiting for the end of its unbonding period
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StakeDecrease {
+    pub amount: Stake,
+    pub available_at: u64,
+}
+
+// @dev Time the contract was paused, `end` is None while the pause lasts
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PausePeriod {
+    pub start: u64,
+    pub end: Option<u64>,
+}
+
+// @dev Roles granted by the owner, the owner can always act as any role
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    Admin,
+    Pauser,
+    FeeManager,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(crate = "near_sdk::serde")]
 pub enum Module {
     TextPrompting,
     ObjectRecognition,
 }
-#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug)]
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct ValidatorProposal {
     pub proposal_hash: Hash,
@@ -97,22 +347,422 @@ pub struct ValidatorProposal {
     pub miner_addresses: Vec<AccountId>,
 }
 
+// @dev Duration of every phase of a request in nanoseconds
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PhaseDurations {
+    pub commit_miner: u64,
+    pub reveal_miner: u64,
+    pub commit_validator: u64,
+    pub reveal_validator: u64,
+}
+
+impl Default for PhaseDurations {
+    fn default() -> Self {
+        Self {
+            commit_miner: COMMIT_MINER_DURATION,
+            reveal_miner: REVEAL_MINER_DURATION,
+            commit_validator: COMMIT_VALIDATOR_DURATION,
+            reveal_validator: REVEAL_VALIDATOR_DURATION,
+        }
+    }
+}
+
+impl PhaseDurations {
+    pub fn is_within_bounds(&self) -> bool {
+        [self.commit_miner, self.reveal_miner, self.commit_validator, self.reveal_validator]
+            .iter()
+            .all(|duration| (MIN_PHASE_DURATION..=MAX_PHASE_DURATION).contains(duration))
+    }
+}
+
+// @dev Economic parameters that can be changed without redeploying the contract
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Config {
+    pub min_miner_stake: NearToken,
+    pub min_validator_stake: NearToken,
+    pub protocol_registration_fee: NearToken,
+    pub accept_legacy_commitments: bool,
+    pub max_ranking_size: u32,
+    pub vote_weighting: VoteWeighting,
+    pub committee: Option<CommitteeConfig>,
+    // @dev Share of the registration fee refunded to a protocol that deregisters, the rest stays in the contract account
+    pub deregistration_refund_basis_points: u32,
+    // @dev Time in nanoseconds between an unstake or a stake decrease and its withdrawal
+    pub unbonding_period: u64,
+    // @dev Share of the stake taken from a miner or validator that commits but doesn't reveal
+    pub slash_basis_points: u32,
+    // @dev Smallest deposit a protocol attaches to a request, it becomes the reward pool of the request
+    pub min_request_fee: NearToken,
+    // @dev Share of the reward pool split between the top miners, the rest goes to the validators
+    pub miner_reward_basis_points: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_miner_stake: MIN_MINER_STAKE,
+            min_validator_stake: MIN_VALIDATOR_STAKE,
+            protocol_registration_fee: PROTOCOL_REGISTRATION_FEE,
+            accept_legacy_commitments: true,
+            max_ranking_size: DEFAULT_RANKING_SIZE,
+            vote_weighting: VoteWeighting::Equal,
+            committee: None,
+            deregistration_refund_basis_points: DEREGISTRATION_REFUND_BASIS_POINTS,
+            unbonding_period: UNBONDING_PERIOD,
+            slash_basis_points: SLASH_BASIS_POINTS,
+            min_request_fee: MIN_REQUEST_FEE,
+            miner_reward_basis_points: MINER_REWARD_BASIS_POINTS,
+        }
+    }
+}
+
+impl Config {
+    pub fn is_within_bounds(&self) -> bool {
+        [
+            self.min_miner_stake,
+            self.min_validator_stake,
+            self.protocol_registration_fee,
+            self.min_request_fee,
+        ]
+        .iter()
+        .all(|amount| (MIN_CONFIG_AMOUNT..=MAX_CONFIG_AMOUNT).contains(amount))
+            && (MIN_RANKING_SIZE..=MAX_RANKING_SIZE).contains(&self.max_ranking_size)
+            && self.vote_weighting.is_within_bounds()
+            && self.committee.is_none_or(|committee| committee.is_within_bounds(self.max_ranking_size))
+            && u128::from(self.deregistration_refund_basis_points) <= BASIS_POINTS
+            && (MIN_UNBONDING_PERIOD..=MAX_UNBONDING_PERIOD).contains(&self.unbonding_period)
+            && u128::from(self.slash_basis_points) <= BASIS_POINTS
+            && u128::from(self.miner_reward_basis_points) <= BASIS_POINTS
+    }
+
+    // @dev Copy of the config with the minimum stakes, the registration fee and the request fee of `other`
+    pub const fn with_amounts_of(&self, other: &Self) -> Self {
+        Self {
+            min_miner_stake: other.min_miner_stake,
+            min_validator_stake: other.min_validator_stake,
+            protocol_registration_fee: other.protocol_registration_fee,
+            min_request_fee: other.min_request_fee,
+            ..*self
+        }
+    }
+
+    pub fn deregistration_refund(&self, registration_fee: NearToken) -> NearToken {
+        NearToken::from_yoctonear(registration_fee.as_yoctonear() * u128::from(self.deregistration_refund_basis_points) / BASIS_POINTS)
+    }
+
+    pub fn miners_reward_share(&self, pool: u128) -> u128 {
+        pool * u128::from(self.miner_reward_basis_points) / BASIS_POINTS
+    }
+
+    pub fn slash_amount(&self, stake: NearToken) -> NearToken {
+        NearToken::from_yoctonear(stake.as_yoctonear() * u128::from(self.slash_basis_points) / BASIS_POINTS)
+    }
+
+    // @dev Requests that don't choose a ranking size get the default one, lowered to the maximum if needed
+    pub fn default_ranking_size(&self) -> u32 {
+        DEFAULT_RANKING_SIZE.min(self.max_ranking_size)
+    }
+
+    pub fn accepts_ranking_size(&self, ranking_size: u32) -> bool {
+        (MIN_RANKING_SIZE..=self.max_ranking_size).contains(&ranking_size)
+    }
+
+    pub const fn min_stake(&self, participant: Participant) -> Stake {
+        match participant {
+            Participant::Miner => self.min_miner_stake,
+            Participant::Validator => self.min_validator_stake,
+        }
+    }
+
+    // @dev Unsalted commitments stay accepted while participants move to the salted format
+    pub const fn accepts_commitment(&self, version: CommitmentVersion) -> bool {
+        matches!(version, CommitmentVersion::V2) || self.accept_legacy_commitments
+    }
+}
+
+// @dev Weight of the ranking of a validator, requests keep the weighting of the config when they are created.
+// Stakes are counted in whole NEAR, the capped and square root curves limit the weight of the largest stakes.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub enum VoteWeighting {
+    // @dev Every validator has a weight of 1
+    #[default]
+    Equal,
+    // @dev The stake, up to the cap
+    Capped {
+        cap: NearToken,
+    },
+    // @dev The square root of the stake
+    SquareRoot,
+}
+
+impl VoteWeighting {
+    pub fn is_within_bounds(&self) -> bool {
+        match self {
+            Self::Capped { cap } => (MIN_CONFIG_AMOUNT..=MAX_CONFIG_AMOUNT).contains(cap),
+            Self::Equal | Self::SquareRoot => true,
+        }
+    }
+
+    // @dev Every validator weighs at least 1, even with a stake below 1 NEAR
+    pub fn weight(&self, stake: Stake) -> Votes {
+        let weight = match self {
+            Self::Equal => 1,
+            Self::Capped { cap } => stake.min(*cap).as_near(),
+            Self::SquareRoot => stake.as_near().isqrt(),
+        };
+
+        weight.max(1) as Votes
+    }
+}
+
+// @dev Number of miners and validators sampled for every new request. A committee always has enough miners
+// for the largest ranking, so validators can rank committee members only.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CommitteeConfig {
+    pub miners: u32,
+    pub validators: u32,
+    // @dev Accounts are drawn with a probability proportional to their stake instead of uniformly
+    pub stake_weighted: bool,
+}
+
+impl CommitteeConfig {
+    pub fn is_within_bounds(&self, max_ranking_size: u32) -> bool {
+        (max_ranking_size.max(MIN_COMMITTEE_SIZE)..=MAX_COMMITTEE_SIZE).contains(&self.miners)
+            && (MIN_COMMITTEE_SIZE..=MAX_COMMITTEE_SIZE).contains(&self.validators)
+    }
+
+    pub const fn size(&self, participant: Participant) -> u32 {
+        match participant {
+            Participant::Miner => self.miners,
+            Participant::Validator => self.validators,
+        }
+    }
+}
+
+// @dev Accounts selected when the request was created, only they can commit to it
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Committee {
+    pub miners: Vec<AccountId>,
+    pub validators: Vec<AccountId>,
+}
+
+impl Committee {
+    pub fn contains(&self, participant: Participant, account_id: &AccountId) -> bool {
+        match participant {
+            Participant::Miner => self.miners.contains(account_id),
+            Participant::Validator => self.validators.contains(account_id),
+        }
+    }
+}
+
+// @dev Values or guidelines miners follow for the requests of a protocol. The document is stored off-chain and identified
+// by the sha256 hash of its content, the tags let miners pick the prompt context without fetching it.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CultureProfile {
+    pub document_hash: Hash,
+    pub tags: Vec<String>,
+}
+
+impl CultureProfile {
+    pub fn is_valid(&self) -> bool {
+        self.document_hash.len() == CONTENT_HASH_LENGTH
+            && self.document_hash.bytes().all(|byte| byte.is_ascii_hexdigit())
+            && self.tags.len() <= MAX_CULTURE_TAGS
+            && self.tags.iter().all(|tag| !tag.is_empty() && tag.len() <= MAX_CULTURE_TAG_LENGTH)
+    }
+}
+
+// @dev Format of the hash committed by miners and validators
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum CommitmentVersion {
+    // @dev Fields concatenated as text without a salt, only accepted while `accept_legacy_commitments` is enabled
+    V1,
+    // @dev Borsh encoding of a domain tag and every field, so each one is length-prefixed, plus a mandatory salt
+    V2,
+}
+
+impl CommitmentVersion {
+    // @dev Reveals carrying a salt are checked against the salted format
+    pub const fn from_salt(salt: Option<&String>) -> Self {
+        match salt {
+            Some(_) => Self::V2,
+            None => Self::V1,
+        }
+    }
+}
+
+// @dev Every request keeps its collections under its own prefix, derived from the request id
+#[derive(BorshSerialize, BorshStorageKey)]
+pub enum StorageKey {
+    MinerProposals { request_id: Hash },
+    ValidatorProposals { request_id: Hash },
+    VotesForMiners { request_id: Hash },
+    RequestsByProtocol { protocol: AccountId },
+    MinersByModule { module: Module },
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Debug)]
 pub struct Request {
     pub sender: AccountId,
     pub request_id: String,
     pub start_time: u64,
+    pub timing: PhaseDurations,
+    pub answer_kind: AnswerKind,
+    pub ranking_size: u32,
+    pub scoring: ScoringMode,
+    pub vote_weighting: VoteWeighting,
+    // @dev Requests created without a committee accept every registered account
+    pub committee: Option<Committee>,
+    // @dev Requests created without a module accept miners of every module
+    pub module: Option<Module>,
+    // @dev Culture and version of the protocol when the request was created, later updates don't change them.
+    // Requests created before culture profiles have none and version 0.
+    pub culture: Option<CultureProfile>,
+    pub protocol_version: u32,
     pub miners_proposals: LookupMap<AccountId, MinerProposal>,
     pub validators_proposals: LookupMap<AccountId, ValidatorProposal>,
-    pub votes_for_miners: LookupMap<AccountId, i32>,
+    pub votes_for_miners: LookupMap<AccountId, Votes>,
     pub miner_keys: Vec<AccountId>,
-    pub top_ten: Vec<(AccountId, i32)>,
+    pub top_miners: Vec<(AccountId, Votes)>,
+    pub committed_miners: Vec<AccountId>,
+    pub committed_validators: Vec<AccountId>,
+    pub is_settled: bool,
+    pub reward_pool: NearToken,
+    pub rewards_distributed: bool,
+    pub result: Option<DecisionResult>,
+    pub delivery: Option<DecisionDelivery>,
+}
+
+impl Request {
+    pub fn count_revealed_miners(&self) -> u32 {
+        self.committed_miners
+            .iter()
+            .filter(|miner| self.miners_proposals.get(*miner).is_some_and(|proposal| proposal.is_revealed))
+            .count() as u32
+    }
+
+    pub fn count_revealed_validators(&self) -> u32 {
+        self.committed_validators
+            .iter()
+            .filter(|validator| self.validators_proposals.get(*validator).is_some_and(|proposal| proposal.is_revealed))
+            .count() as u32
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum DeliveryStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+// @dev Delivery of the decision to the protocol that sent the request
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DecisionDelivery {
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RequestSummary {
+    pub sender: AccountId,
+    pub request_id: String,
+    pub start_time: u64,
+    pub timing: PhaseDurations,
+    pub answer_kind: AnswerKind,
+    pub ranking_size: u32,
+    pub scoring: ScoringMode,
+    pub vote_weighting: VoteWeighting,
+    pub module: Option<Module>,
+    pub culture: Option<CultureProfile>,
+    pub protocol_version: u32,
+    pub stage: RequestState,
+    pub committed_miners: u32,
+    pub revealed_miners: u32,
+    pub committed_validators: u32,
+    pub revealed_validators: u32,
+    pub reward_pool: NearToken,
+    pub is_settled: bool,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Debug)]
 pub struct Protocol {
     pub account: AccountId,
-    pub culture: String,
+    // @dev Protocols registered with a free text culture have no profile until they update it
+    pub culture: Option<CultureProfile>,
     pub modules: Vec<Module>,
     pub registration_fee: NearToken,
+    pub timing: PhaseDurations,
+    // @dev Starts at 1 and increases with every update of the protocol
+    pub version: u32,
+}
+
+// @dev The root state and the requests and protocols are stored tagged with the version of their layout,
+// a new layout is added as a new variant. Entries written by the first version, before the tag existed, are rewritten by the migration methods.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateVersion {
+    V1,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum VersionedRequest {
+    V1(Request),
+}
+
+// @dev Requests of the first version have no tag and can't be deserialized, reading one aborts until `migrate_request_storage` rewrites it
+impl VersionedRequest {
+    pub const fn current(&self) -> &Request {
+        match self {
+            Self::V1(request) => request,
+        }
+    }
+
+    pub const fn current_mut(&mut self) -> &mut Request {
+        match self {
+            Self::V1(request) => request,
+        }
+    }
+}
+
+impl From<Request> for VersionedRequest {
+    fn from(request: Request) -> Self {
+        Self::V1(request)
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum VersionedProtocol {
+    V1(Protocol),
+}
+
+// @dev Protocols of the first version have no tag, they are detected before they are read and can't create requests
+// until `migrate_protocols` rewrites them
+impl VersionedProtocol {
+    pub const fn current(&self) -> &Protocol {
+        match self {
+            Self::V1(protocol) => protocol,
+        }
+    }
+
+    pub const fn current_mut(&mut self) -> &mut Protocol {
+        match self {
+            Self::V1(protocol) => protocol,
+        }
+    }
+}
+
+impl From<Protocol> for VersionedProtocol {
+    fn from(protocol: Protocol) -> Self {
+        Self::V1(protocol)
+    }
 }
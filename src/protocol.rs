@@ -0,0 +1,201 @@
+use near_sdk::{env, log, near_bindgen, AccountId, FunctionError};
+
+use crate::{
+    AddModuleResult, Contract, ContractError, ContractExt, CultureProfile, DeregisterProtocolResult, EventLog, EventLogVariant, Hash, Module, ModuleAddedLog,
+    ModuleRemovedLog, Protocol, ProtocolDeregisteredLog, ProtocolUpdatedLog, RemoveModuleResult, UpdateProtocolResult, VersionedProtocol,
+};
+
+#[near_bindgen]
+impl Contract {
+    // @dev Replaces the culture of the protocol and starts a new version, requests created before keep the version they started with
+    pub fn update_protocol(&mut self, culture: CultureProfile) -> UpdateProtocolResult {
+        self.assert_not_paused();
+
+        let account = env::predecessor_account_id();
+
+        if !culture.is_valid() {
+            ContractError::InvalidCultureProfile.panic();
+        }
+
+        let Some(protocol) = self.registered_protocol_mut(&account) else {
+            log!("Protocol not registered: {}", account);
+            return UpdateProtocolResult::NotRegistered;
+        };
+
+        protocol.culture = Some(culture.clone());
+        protocol.version += 1;
+        let version = protocol.version;
+
+        let protocol_updated_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::ProtocolUpdated(vec![ProtocolUpdatedLog { account, version, culture }]),
+        };
+
+        log!(&protocol_updated_log.to_string());
+
+        UpdateProtocolResult::Success
+    }
+
+    // @dev New requests can name the module once it is added, it starts a new version of the protocol
+    pub fn add_module(&mut self, module: Module) -> AddModuleResult {
+        self.assert_not_paused();
+
+        let account = env::predecessor_account_id();
+
+        let Some(protocol) = self.registered_protocol_mut(&account) else {
+            log!("Protocol not registered: {}", account);
+            return AddModuleResult::NotRegistered;
+        };
+
+        if protocol.modules.contains(&module) {
+            log!("Module already supported: {:?}", module);
+            return AddModuleResult::AlreadySupported;
+        }
+
+        protocol.modules.push(module);
+        protocol.version += 1;
+        let version = protocol.version;
+
+        let module_added_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::ModuleAdded(vec![ModuleAddedLog { account, version, module }]),
+        };
+
+        log!(&module_added_log.to_string());
+
+        AddModuleResult::Success
+    }
+
+    // @dev Requests already created for the module keep it, a protocol always keeps at least one module
+    pub fn remove_module(&mut self, module: Module) -> RemoveModuleResult {
+        self.assert_not_paused();
+
+        let account = env::predecessor_account_id();
+
+        let Some(protocol) = self.registered_protocol_mut(&account) else {
+            log!("Protocol not registered: {}", account);
+            return RemoveModuleResult::NotRegistered;
+        };
+
+        if !protocol.modules.contains(&module) {
+            log!("Module not supported: {:?}", module);
+            return RemoveModuleResult::NotSupported;
+        }
+
+        if protocol.modules.len() == 1 {
+            log!("Protocol can't remove its last module: {}", account);
+            return RemoveModuleResult::LastModule;
+        }
+
+        protocol.modules.retain(|supported| *supported != module);
+        protocol.version += 1;
+        let version = protocol.version;
+
+        let module_removed_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::ModuleRemoved(vec![ModuleRemovedLog { account, version, module }]),
+        };
+
+        log!(&module_removed_log.to_string());
+
+        RemoveModuleResult::Success
+    }
+
+    // @dev Refunds the configured share of the registration fee once every request of the protocol is finalized.
+    // The requests stay readable and listed for the protocol, the account can register again later.
+    pub fn deregister_protocol(&mut self) -> DeregisterProtocolResult {
+        let account = env::predecessor_account_id();
+
+        let Some(protocol) = self.registered_protocol_mut(&account) else {
+            log!("Protocol not registered: {}", account);
+            return DeregisterProtocolResult::NotRegistered;
+        };
+        let registration_fee = protocol.registration_fee;
+
+        if self.has_unfinished_requests(&account) {
+            log!("Protocol has unfinished requests: {}", account);
+            return DeregisterProtocolResult::UnfinishedRequests;
+        }
+
+        self.protocols.remove(&account);
+        self.protocol_index.remove(&account);
+
+        let refund = self.config.deregistration_refund(registration_fee);
+        Self::refund_deposit(account.clone(), refund);
+
+        let protocol_deregistered_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::ProtocolDeregistered(vec![ProtocolDeregisteredLog { account, refund }]),
+        };
+
+        log!(&protocol_deregistered_log.to_string());
+
+        DeregisterProtocolResult::Success
+    }
+
+    pub fn get_protocol_culture(&self, protocol_id: AccountId) -> Option<CultureProfile> {
+        self.registered_protocol(&protocol_id).and_then(|protocol| protocol.culture.clone())
+    }
+
+    pub fn get_protocol_version(&self, protocol_id: AccountId) -> Option<u32> {
+        self.registered_protocol(&protocol_id).map(|protocol| protocol.version)
+    }
+
+    pub fn get_protocol_modules(&self, protocol_id: AccountId) -> Vec<Module> {
+        self.registered_protocol(&protocol_id)
+            .map(|protocol| protocol.modules.clone())
+            .unwrap_or_default()
+    }
+
+    // @dev Nonce of the next request of the protocol, requests are never removed from the list of the protocol so it only grows.
+    // Requests indexed by `migrate_request_storage` take the nonce of their position.
+    pub fn get_request_nonce(&self, protocol_id: AccountId) -> u64 {
+        self.requests_by_protocol.get(&protocol_id).map_or(0, |requests| u64::from(requests.len()))
+    }
+
+    pub fn get_request_id_by_nonce(&self, protocol_id: AccountId, nonce: u64) -> Option<Hash> {
+        let index = u32::try_from(nonce).ok()?;
+        self.requests_by_protocol.get(&protocol_id).and_then(|requests| requests.get(index)).cloned()
+    }
+}
+
+impl Contract {
+    // @dev Protocols of the first version read as not registered until they are migrated
+    fn registered_protocol(&self, account: &AccountId) -> Option<&Protocol> {
+        if Self::is_unmigrated_protocol(account) {
+            return None;
+        }
+
+        self.protocols.get(account).map(VersionedProtocol::current)
+    }
+
+    // @dev Protocols of the first version have to be migrated before they can change
+    fn registered_protocol_mut(&mut self, account: &AccountId) -> Option<&mut Protocol> {
+        if Self::is_unmigrated_protocol(account) {
+            ContractError::ProtocolNotMigrated(account.clone()).panic();
+        }
+
+        self.protocols.get_mut(account).map(VersionedProtocol::current_mut)
+    }
+
+    // @dev A request is finished once its decision is computed. Requests are counted when they are indexed,
+    // so requests of the first version count once `migrate_request_storage` indexes them.
+    fn has_unfinished_requests(&self, protocol: &AccountId) -> bool {
+        self.unfinished_requests.get(protocol).is_some_and(|unfinished| *unfinished > 0)
+    }
+
+    pub(crate) fn finish_request(&mut self, protocol: &AccountId) {
+        match self.unfinished_requests.get(protocol).copied() {
+            Some(unfinished) if unfinished > 1 => {
+                self.unfinished_requests.insert(protocol.clone(), unfinished - 1);
+            }
+            _ => {
+                self.unfinished_requests.remove(protocol);
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use near_sdk::{env, log, near_bindgen, AccountId, NearToken, Promise};
+use std::collections::HashSet;
+
+use crate::{ClaimRewardsLog, ClaimRewardsResult, Config, Contract, ContractExt, EventLog, EventLogVariant, Hash, Request, RewardDistributedLog};
+
+#[near_bindgen]
+impl Contract {
+    pub fn claim_rewards(&mut self) -> ClaimRewardsResult {
+        let account = env::predecessor_account_id();
+
+        let amount = self.rewards.remove(&account).unwrap_or(NearToken::from_yoctonear(0));
+
+        if amount.as_yoctonear() == 0 {
+            log!("No rewards to claim: {}", account);
+            return ClaimRewardsResult::NothingToClaim;
+        }
+
+        Promise::new(account.clone()).transfer(amount);
+
+        let claim_rewards_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::ClaimRewards(vec![ClaimRewardsLog { account, amount }]),
+        };
+
+        log!(&claim_rewards_log.to_string());
+
+        ClaimRewardsResult::Success
+    }
+
+    pub fn get_rewards(&self, account_id: AccountId) -> NearToken {
+        self.rewards.get(&account_id).copied().unwrap_or(NearToken::from_yoctonear(0))
+    }
+}
+
+impl Contract {
+    // @dev Splits the reward pool between the top miners, in equal parts, and the validators whose revealed answer
+    // overlaps the top miners, in proportion to the overlap. If nobody voted, the pool goes back to the requester.
+    pub(crate) fn compute_reward_credits(request: &Request, config: &Config) -> Vec<(AccountId, NearToken)> {
+        let pool = request.reward_pool.as_yoctonear();
+
+        if request.top_miners.is_empty() {
+            return vec![(request.sender.clone(), request.reward_pool)];
+        }
+
+        let top_miners: HashSet<&AccountId> = request.top_miners.iter().map(|(miner, _)| miner).collect();
+
+        let honest_validators: Vec<(AccountId, u128)> = request
+            .committed_validators
+            .iter()
+            .filter_map(|validator| {
+                let proposal = request.validators_proposals.get(validator).filter(|proposal| proposal.is_revealed)?;
+                let overlap = proposal.miner_addresses.iter().filter(|miner| top_miners.contains(miner)).count() as u128;
+                (overlap > 0).then(|| (validator.clone(), overlap))
+            })
+            .collect();
+
+        let miners_share = if honest_validators.is_empty() {
+            pool
+        } else {
+            config.miners_reward_share(pool)
+        };
+        let validators_share = pool - miners_share;
+        let total_overlap: u128 = honest_validators.iter().map(|(_, overlap)| overlap).sum();
+
+        let per_miner = miners_share / request.top_miners.len() as u128;
+        let mut credits: Vec<(AccountId, NearToken)> = request
+            .top_miners
+            .iter()
+            .map(|(miner, _)| (miner.clone(), NearToken::from_yoctonear(per_miner)))
+            .collect();
+
+        for (validator, overlap) in honest_validators {
+            credits.push((validator, NearToken::from_yoctonear(validators_share * overlap / total_overlap)));
+        }
+
+        credits.retain(|(_, amount)| amount.as_yoctonear() > 0);
+        credits
+    }
+
+    pub(crate) fn distribute_rewards(&mut self, request_id: Hash, credits: Vec<(AccountId, NearToken)>) {
+        if credits.is_empty() {
+            return;
+        }
+
+        let mut reward_logs = Vec::new();
+
+        for (account, amount) in credits {
+            let balance = self.get_rewards(account.clone());
+            self.rewards.insert(account.clone(), balance.saturating_add(amount));
+
+            reward_logs.push(RewardDistributedLog {
+                request_id: request_id.clone(),
+                account,
+                amount,
+            });
+        }
+
+        let reward_distributed_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::RewardDistributed(reward_logs),
+        };
+
+        env::log_str(&reward_distributed_log.to_string());
+    }
+}
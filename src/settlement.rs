@@ -0,0 +1,90 @@
+use near_sdk::{env, log, near_bindgen, AccountId};
+
+use crate::{Contract, ContractExt, EventLog, EventLogVariant, Hash, Participant, RequestState, SettleRequestResult, SlashLog, VersionedRequest};
+
+#[near_bindgen]
+impl Contract {
+    // @dev Permissionless step, once the request ended anyone can slash the miners and validators that committed but never revealed.
+    // The slashed amount stays in the contract account.
+    pub fn settle_request(&mut self, request_id: Hash) -> SettleRequestResult {
+        let Some(request) = self.requests.get_mut(&request_id).map(VersionedRequest::current_mut) else {
+            log!("Request is not registered: {}", request_id);
+            return SettleRequestResult::RequestNotFound;
+        };
+
+        if Self::get_stage(request) != RequestState::Ended {
+            log!("Request has not ended: {}", request_id);
+            return SettleRequestResult::NotEnded;
+        }
+
+        if request.is_settled {
+            log!("Request already settled: {}", request_id);
+            return SettleRequestResult::AlreadySettled;
+        }
+
+        request.is_settled = true;
+
+        // @dev Reveals are blocked while the contract is paused, nobody is slashed for a reveal window that overlapped a pause
+        let timing = request.timing;
+        let reveal_miners_start = request.start_time + timing.commit_miner;
+        let reveal_miners_end = reveal_miners_start + timing.reveal_miner;
+        let reveal_validators_start = reveal_miners_end + timing.commit_validator;
+        let reveal_validators_end = reveal_validators_start + timing.reveal_validator;
+
+        let unrevealed_miners: Vec<AccountId> = request
+            .committed_miners
+            .iter()
+            .filter(|miner| !request.miners_proposals.get(*miner).is_some_and(|proposal| proposal.is_revealed))
+            .cloned()
+            .collect();
+
+        let unrevealed_validators: Vec<AccountId> = request
+            .committed_validators
+            .iter()
+            .filter(|validator| !request.validators_proposals.get(*validator).is_some_and(|proposal| proposal.is_revealed))
+            .cloned()
+            .collect();
+
+        // @dev A settled request has nothing left to do, so it stops being listed as active
+        self.active_requests.remove(&request_id);
+
+        if !self.was_paused_during(reveal_miners_start, reveal_miners_end) {
+            for miner in unrevealed_miners {
+                self.slash(&request_id, Participant::Miner, miner);
+            }
+        }
+
+        if !self.was_paused_during(reveal_validators_start, reveal_validators_end) {
+            for validator in unrevealed_validators {
+                self.slash(&request_id, Participant::Validator, validator);
+            }
+        }
+
+        SettleRequestResult::Success
+    }
+}
+
+impl Contract {
+    fn slash(&mut self, request_id: &Hash, participant: Participant, account: AccountId) {
+        let config = self.config;
+        let Some(stake) = self.stakes_mut(participant).get_mut(&account) else {
+            return;
+        };
+
+        let amount = config.slash_amount(*stake);
+        *stake = stake.saturating_sub(amount);
+
+        let slash_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::Slash(vec![SlashLog {
+                request_id: request_id.clone(),
+                account,
+                participant,
+                amount,
+            }]),
+        };
+
+        env::log_str(&slash_log.to_string());
+    }
+}
@@ -0,0 +1,305 @@
+use near_sdk::store::LookupMap;
+use near_sdk::{env, log, near_bindgen, AccountId, FunctionError, NearToken, Promise};
+
+use crate::indexed_set::IndexedSet;
+use crate::{
+    AddStakeLog, AddStakeResult, Contract, ContractError, ContractExt, DecreaseStakeLog, DecreaseStakeResult, EventLog, EventLogVariant, Hash, Participant,
+    RequestState, RequestUnstakeLog, RequestUnstakeResult, Stake, StakeDecrease, WithdrawStakeLog, WithdrawStakeResult,
+};
+
+#[near_bindgen]
+impl Contract {
+    // @dev Starts the unbonding period of the caller's stake. The account stays registered until the stake is withdrawn,
+    // so it can still reveal the commits it already has, but it can't commit to new requests.
+    pub fn request_unstake(&mut self, participant: Participant) -> RequestUnstakeResult {
+        let account = env::predecessor_account_id();
+
+        let Some(amount) = self.get_stake(participant, account.clone()) else {
+            log!("Account not registered: {}", account);
+            return RequestUnstakeResult::NotRegistered;
+        };
+
+        if self.is_unbonding(participant, account.clone()) {
+            log!("Unstake already requested: {}", account);
+            return RequestUnstakeResult::AlreadyRequested;
+        }
+
+        let available_at = env::block_timestamp() + self.config.unbonding_period;
+        self.unbonding.insert((participant, account.clone()), available_at);
+
+        let request_unstake_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::RequestUnstake(vec![RequestUnstakeLog {
+                account,
+                participant,
+                amount,
+                available_at,
+            }]),
+        };
+
+        log!(&request_unstake_log.to_string());
+
+        RequestUnstakeResult::Success
+    }
+
+    // @dev Transfers the stake back once the unbonding period is over and every request the account committed to has ended.
+    // Unrevealed commits also keep the stake locked until the request is settled.
+    pub fn withdraw_stake(&mut self, participant: Participant) -> WithdrawStakeResult {
+        let account = env::predecessor_account_id();
+        let key = (participant, account.clone());
+
+        let Some(available_at) = self.unbonding.get(&key).copied() else {
+            log!("Unstake not requested: {}", account);
+            return WithdrawStakeResult::NotRequested;
+        };
+
+        if env::block_timestamp() < available_at {
+            log!("Stake is unbonding until: {}", available_at);
+            return WithdrawStakeResult::StillUnbonding;
+        }
+
+        if self.has_open_commits(participant, &account) {
+            log!("Account has open commits: {}", account);
+            return WithdrawStakeResult::OpenCommits;
+        }
+
+        let amount = self.stakes_mut(participant).remove(&account).unwrap_or_else(|| panic!("Stake not found"));
+
+        self.unbonding.remove(&key);
+        self.open_commits.remove(&key);
+        self.index_mut(participant).remove(&account);
+        if participant == Participant::Miner {
+            self.unindex_miner_modules(&account);
+        }
+
+        Promise::new(account.clone()).transfer(amount);
+
+        let withdraw_stake_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::WithdrawStake(vec![WithdrawStakeLog { account, participant, amount }]),
+        };
+
+        log!(&withdraw_stake_log.to_string());
+
+        WithdrawStakeResult::Success
+    }
+
+    // @dev Tops up the stake of a registered account, the deposit is refunded if it can't be added
+    #[payable]
+    pub fn add_stake(&mut self, participant: Participant) -> AddStakeResult {
+        self.assert_not_paused();
+
+        let account = env::predecessor_account_id();
+        let amount = env::attached_deposit();
+
+        if amount.is_zero() {
+            ContractError::ZeroStakeAmount.panic();
+        }
+
+        let Some(stake) = self.get_stake(participant, account.clone()) else {
+            log!("Account not registered: {}", account);
+            Self::refund_deposit(account, amount);
+            return AddStakeResult::NotRegistered;
+        };
+
+        if self.is_unbonding(participant, account.clone()) {
+            log!("Account is unbonding: {}", account);
+            Self::refund_deposit(account, amount);
+            return AddStakeResult::Unbonding;
+        }
+
+        let stake = stake.saturating_add(amount);
+        self.stakes_mut(participant).insert(account.clone(), stake);
+
+        let add_stake_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::AddStake(vec![AddStakeLog {
+                account,
+                participant,
+                amount,
+                stake,
+            }]),
+        };
+
+        log!(&add_stake_log.to_string());
+
+        AddStakeResult::Success
+    }
+
+    // @dev Moves part of the stake to the unbonding queue, the remaining stake must stay above the current minimum.
+    // Accounts with open commits can't decrease, so the stake they committed with is the one that gets slashed.
+    // The decreased amount stops counting for the vote weight right away.
+    pub fn decrease_stake(&mut self, participant: Participant, amount: NearToken) -> DecreaseStakeResult {
+        let account = env::predecessor_account_id();
+
+        if amount.is_zero() {
+            ContractError::ZeroStakeAmount.panic();
+        }
+
+        let Some(stake) = self.get_stake(participant, account.clone()) else {
+            log!("Account not registered: {}", account);
+            return DecreaseStakeResult::NotRegistered;
+        };
+
+        if self.is_unbonding(participant, account.clone()) {
+            log!("Account is unbonding: {}", account);
+            return DecreaseStakeResult::Unbonding;
+        }
+
+        if self.has_open_commits(participant, &account) {
+            log!("Account has open commits: {}", account);
+            return DecreaseStakeResult::OpenCommits;
+        }
+
+        let Some(stake) = stake.checked_sub(amount).filter(|stake| *stake >= self.config.min_stake(participant)) else {
+            log!("Stake would be below the minimum: {}", self.config.min_stake(participant));
+            return DecreaseStakeResult::BelowMinimum;
+        };
+
+        let available_at = env::block_timestamp() + self.config.unbonding_period;
+        let key = (participant, account.clone());
+
+        let mut decreases = self.stake_decreases.get(&key).cloned().unwrap_or_default();
+        decreases.push(StakeDecrease { amount, available_at });
+
+        self.stake_decreases.insert(key, decreases);
+        self.stakes_mut(participant).insert(account.clone(), stake);
+
+        let decrease_stake_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::DecreaseStake(vec![DecreaseStakeLog {
+                account,
+                participant,
+                amount,
+                stake,
+                available_at,
+            }]),
+        };
+
+        log!(&decrease_stake_log.to_string());
+
+        DecreaseStakeResult::Success
+    }
+
+    // @dev Transfers back every decrease whose unbonding period is over, the others stay queued.
+    // Decreased amounts already left the stake, so open commits can't be slashed from them and don't hold them back.
+    pub fn withdraw_stake_decreases(&mut self, participant: Participant) -> WithdrawStakeResult {
+        let account = env::predecessor_account_id();
+        let key = (participant, account.clone());
+
+        let Some(decreases) = self.stake_decreases.get(&key).cloned() else {
+            log!("Stake decrease not requested: {}", account);
+            return WithdrawStakeResult::NotRequested;
+        };
+
+        let (available, pending): (Vec<_>, Vec<_>) = decreases.into_iter().partition(|decrease| decrease.available_at <= env::block_timestamp());
+
+        if available.is_empty() {
+            let available_at = pending.iter().map(|decrease| decrease.available_at).min().unwrap_or_default();
+            log!("Stake is unbonding until: {}", available_at);
+            return WithdrawStakeResult::StillUnbonding;
+        }
+
+        let amount = available
+            .iter()
+            .fold(NearToken::from_yoctonear(0), |total, decrease| total.saturating_add(decrease.amount));
+
+        if pending.is_empty() {
+            self.stake_decreases.remove(&key);
+        } else {
+            self.stake_decreases.insert(key, pending);
+        }
+
+        Promise::new(account.clone()).transfer(amount);
+
+        let withdraw_stake_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "2.0.0".to_string(),
+            event: EventLogVariant::WithdrawStake(vec![WithdrawStakeLog { account, participant, amount }]),
+        };
+
+        log!(&withdraw_stake_log.to_string());
+
+        WithdrawStakeResult::Success
+    }
+
+    pub fn get_stake_decreases(&self, participant: Participant, account_id: AccountId) -> Vec<StakeDecrease> {
+        self.stake_decreases.get(&(participant, account_id)).cloned().unwrap_or_default()
+    }
+
+    pub fn get_stake(&self, participant: Participant, account_id: AccountId) -> Option<Stake> {
+        self.stakes(participant).get(&account_id).copied()
+    }
+
+    pub fn is_unbonding(&self, participant: Participant, account_id: AccountId) -> bool {
+        self.unbonding.contains_key(&(participant, account_id))
+    }
+}
+
+impl Contract {
+    pub(crate) const fn stakes(&self, participant: Participant) -> &LookupMap<AccountId, Stake> {
+        match participant {
+            Participant::Miner => &self.miners,
+            Participant::Validator => &self.validators,
+        }
+    }
+
+    pub(crate) const fn stakes_mut(&mut self, participant: Participant) -> &mut LookupMap<AccountId, Stake> {
+        match participant {
+            Participant::Miner => &mut self.miners,
+            Participant::Validator => &mut self.validators,
+        }
+    }
+
+    pub(crate) const fn index_mut(&mut self, participant: Participant) -> &mut IndexedSet<AccountId> {
+        match participant {
+            Participant::Miner => &mut self.miner_index,
+            Participant::Validator => &mut self.validator_index,
+        }
+    }
+
+    // @dev Deposits attached to calls that didn't use them are sent back to the caller
+    pub(crate) fn refund_deposit(account_id: AccountId, amount: NearToken) {
+        if !amount.is_zero() {
+            Promise::new(account_id).transfer(amount);
+        }
+    }
+
+    pub(crate) fn track_open_commit(&mut self, participant: Participant, account_id: AccountId, request_id: Hash) {
+        let key = (participant, account_id);
+
+        let mut commits = self.open_commits.get(&key).cloned().unwrap_or_default();
+        commits.retain(|id| self.is_commit_open(participant, &key.1, id));
+        commits.push(request_id);
+
+        self.open_commits.insert(key, commits);
+    }
+
+    fn has_open_commits(&self, participant: Participant, account_id: &AccountId) -> bool {
+        self.open_commits
+            .get(&(participant, account_id.clone()))
+            .is_some_and(|commits| commits.iter().any(|id| self.is_commit_open(participant, account_id, id)))
+    }
+
+    fn is_commit_open(&self, participant: Participant, account_id: &AccountId, request_id: &Hash) -> bool {
+        let Some(request) = self.get_request(request_id) else {
+            return false;
+        };
+
+        if Self::get_stage(request) != RequestState::Ended {
+            return true;
+        }
+
+        // @dev An unrevealed commit stays open until the request is settled, so the stake can still be slashed
+        let is_revealed = match participant {
+            Participant::Miner => request.miners_proposals.get(account_id).is_some_and(|proposal| proposal.is_revealed),
+            Participant::Validator => request.validators_proposals.get(account_id).is_some_and(|proposal| proposal.is_revealed),
+        };
+
+        !request.is_settled && !is_revealed
+    }
+}
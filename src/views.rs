@@ -0,0 +1,71 @@
+use near_sdk::{near_bindgen, AccountId};
+
+use crate::{Contract, ContractExt, DecisionResult, Hash, MinerProposal, Request, RequestSummary, ValidatorProposal, Votes};
+
+#[near_bindgen]
+impl Contract {
+    pub fn get_request_summary(&self, request_id: Hash) -> Option<RequestSummary> {
+        let request = self.get_request(&request_id)?;
+
+        Some(RequestSummary {
+            sender: request.sender.clone(),
+            request_id: request.request_id.clone(),
+            start_time: request.start_time,
+            timing: request.timing,
+            answer_kind: request.answer_kind,
+            ranking_size: request.ranking_size,
+            scoring: request.scoring.clone(),
+            vote_weighting: request.vote_weighting,
+            module: request.module,
+            culture: request.culture.clone(),
+            protocol_version: request.protocol_version,
+            stage: Self::get_stage(request),
+            committed_miners: request.committed_miners.len() as u32,
+            revealed_miners: request.count_revealed_miners(),
+            committed_validators: request.committed_validators.len() as u32,
+            revealed_validators: request.count_revealed_validators(),
+            reward_pool: request.reward_pool,
+            is_settled: request.is_settled,
+        })
+    }
+
+    pub fn get_miner_proposal(&self, request_id: Hash, miner_id: AccountId) -> Option<MinerProposal> {
+        self.get_request(&request_id)?.miners_proposals.get(&miner_id).cloned()
+    }
+
+    pub fn get_validator_proposal(&self, request_id: Hash, validator_id: AccountId) -> Option<ValidatorProposal> {
+        self.get_request(&request_id)?.validators_proposals.get(&validator_id).cloned()
+    }
+
+    // @dev The result is stored once the request is finalized
+    pub fn get_decision_result(&self, request_id: Hash) -> Option<DecisionResult> {
+        self.get_request(&request_id)?.result.clone()
+    }
+
+    // @dev Returns None if the request doesn't exist, a miner without votes has 0
+    pub fn get_votes_for_miner(&self, request_id: Hash, miner_id: AccountId) -> Option<Votes> {
+        let request = self.get_request(&request_id)?;
+
+        Some(request.votes_for_miners.get(&miner_id).copied().unwrap_or(0))
+    }
+
+    // @dev Votes of every miner that received at least one vote, in the order they were first voted
+    pub fn get_votes_for_miners(&self, request_id: Hash) -> Option<Vec<(AccountId, Votes)>> {
+        self.get_request(&request_id).map(Self::collect_votes)
+    }
+
+    // @dev The top miners are stored once get_top_voters is called after the request ended
+    pub fn get_top_miners(&self, request_id: Hash) -> Option<Vec<(AccountId, Votes)>> {
+        self.get_request(&request_id).map(|request| request.top_miners.clone())
+    }
+}
+
+impl Contract {
+    pub(crate) fn collect_votes(request: &Request) -> Vec<(AccountId, Votes)> {
+        request
+            .miner_keys
+            .iter()
+            .filter_map(|miner| request.votes_for_miners.get(miner).map(|votes| (miner.clone(), *votes)))
+            .collect()
+    }
+}
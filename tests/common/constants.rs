@@ -7,6 +7,7 @@ pub const MINER_2: &str = "miner2.near";
 pub const VALIDATOR_1: &str = "validator1.near";
 pub const VALIDATOR_2: &str = "validator2.near";
 pub const VALIDATOR_3: &str = "validator3.near";
+pub const OWNER: &str = "owner.near";
 pub const DEFAULT_PROTOCOL_ACCOUNT_ID: &str = ACCOUNT_1;
 pub const DEFAULT_MINER_ACCOUNT_ID: &str = MINER_1;
 pub const DEFAULT_VALIDATOR_ACCOUNT_ID: &str = VALIDATOR_1;
@@ -19,8 +20,13 @@ pub const DEFAULT_MESSAGE_TO_REQUEST: &str = "Should we add this new NFT to our
 pub const DEFAULT_REQUEST_ID: &str = "73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b";
 pub const DEFAULT_MINER_ANSWER: &str = "422fa60e22dc75c98d21bb975323c5c0b854d6b0b7a63d6446b3bbb628b65a5b";
 pub const DEFAULT_VALIDATOR_ANSWER: &str = "475e370cb76d086eb73e1a239ea10df551dc75a6596f61c7c2b473669570eb3b";
+pub const VALIDATOR_2_ANSWER: &str = "773fbc1648f4f84994f716ce22b8a990a6febbe4516d5d7d444fbad8f8ed149f";
+pub const VALIDATOR_3_ANSWER: &str = "88ead563a267bca8b37e7933812f84c47e8d757fdf9ab4cdf92a4cdde3f63a86";
 pub const REVEAL_MINER_TIME: u64 = 100000000 + (4 * 60 * 1_000_000_000);
 pub const COMMIT_VALIDATOR_TIME: u64 = 100000000 + (7 * 60 * 1_000_000_000);
 pub const REVEAL_VALIDATOR_TIME: u64 = 100000000 + (8 * 60 * 1_000_000_000);
 pub const REVEAL_TOPTEN_TIME: u64 = 100000000 + (10 * 60 * 1_000_000_000);
-pub const DEFAULT_CULTURE: &str = "Governance decision";
+pub const DEFAULT_CULTURE_HASH: &str = "d5c53010c394ebd738ac31123aa35bd43ccbaf783b6a27c6df7aa3e0bddd003a";
+pub const DEFAULT_CULTURE_TAG: &str = "governance";
+pub const UNBONDING_TIME: u64 = DEFAULT_TIMESTAMP + (2 * 24 * 60 * 60 * 1_000_000_000);
+pub const CONTRACT_ACCOUNT_ID: &str = "alice.near"; // default current account of the testing environment
@@ -1,8 +1,35 @@
+use earthmind_rs::{AnswerKind, Module, ScoringMode};
+
 #[derive(Debug)]
 pub enum Log {
     Event {
         event_name: String,
         data: Vec<(&'static str, serde_json::Value)>,
     },
+    EventBatch {
+        event_name: String,
+        data: Vec<Vec<(&'static str, serde_json::Value)>>,
+    },
     Message(String),
 }
+
+// @dev Protocol modules and request parameters of `register_request`, the parameters left out take the defaults of the contract
+pub struct RequestParams {
+    pub modules: Vec<Module>,
+    pub answer_kind: Option<AnswerKind>,
+    pub ranking_size: Option<u32>,
+    pub scoring: Option<ScoringMode>,
+    pub module: Option<Module>,
+}
+
+impl Default for RequestParams {
+    fn default() -> Self {
+        Self {
+            modules: vec![Module::TextPrompting],
+            answer_kind: None,
+            ranking_size: None,
+            scoring: None,
+            module: None,
+        }
+    }
+}
@@ -1,10 +1,22 @@
 use super::{
-    constants::{DEFAULT_MINER_ACCOUNT_ID, DEFAULT_PROTOCOL_ACCOUNT_ID, DEFAULT_VALIDATOR_ACCOUNT_ID},
-    types::Log,
+    constants::{
+        COMMIT_VALIDATOR_TIME, DEFAULT_CULTURE_HASH, DEFAULT_CULTURE_TAG, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR,
+        DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ACCOUNT_ID, DEFAULT_PROTOCOL_ACCOUNT_ID, DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ACCOUNT_ID, OWNER,
+        REVEAL_MINER_TIME, REVEAL_VALIDATOR_TIME,
+    },
+    environment::Environment,
+    types::{Log, RequestParams},
 };
-use near_sdk::{test_utils::get_logs, AccountId};
+use earthmind_rs::{Answer, Contract, CultureProfile, Module, ScoringMode};
+use near_sdk::mock::MockAction;
+use near_sdk::test_utils::{get_created_receipts, get_logs};
+use near_sdk::{env, AccountId, NearToken};
 use serde_json::{json, Value};
 
+pub fn get_owner_account() -> AccountId {
+    OWNER.parse().unwrap()
+}
+
 pub fn get_account_for_protocol(account: &str) -> AccountId {
     account.parse().unwrap()
 }
@@ -28,6 +40,57 @@ pub fn get_account_for_validator(validator: &str) -> AccountId {
 pub fn get_default_validator_account() -> AccountId {
     DEFAULT_VALIDATOR_ACCOUNT_ID.parse().unwrap()
 }
+pub fn default_culture() -> CultureProfile {
+    CultureProfile {
+        document_hash: DEFAULT_CULTURE_HASH.to_string(),
+        tags: vec![DEFAULT_CULTURE_TAG.to_string()],
+    }
+}
+
+pub fn register_protocol(contract: &mut Contract, protocol: &AccountId, modules: Vec<Module>) {
+    Environment::with_account(protocol.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(default_culture(), modules, None);
+}
+
+// @dev Registers the default protocol and creates the default request, the environment is left at the protocol account
+pub fn register_request(contract: &mut Contract, params: RequestParams) {
+    register_protocol(contract, &get_default_protocol_account(), params.modules);
+    contract.request_governance_decision(
+        DEFAULT_MESSAGE_TO_REQUEST.to_string(),
+        None,
+        params.answer_kind,
+        params.ranking_size,
+        params.scoring,
+        params.module,
+    );
+}
+
+pub fn get_request_id(protocol: &AccountId, message: &str, nonce: u64) -> String {
+    let request_id = env::keccak256(format!("{}{}", protocol, message).as_bytes());
+    if nonce == 0 {
+        return hex::encode(request_id);
+    }
+
+    hex::encode(env::keccak256(&[request_id, nonce.to_be_bytes().to_vec()].concat()))
+}
+
+pub fn hash_miner_answer(request_id: &str, miner: &AccountId, answer: bool, message: &str) -> String {
+    hex::encode(env::keccak256(format!("{}{}{}{}", request_id, miner, answer, message).as_bytes()))
+}
+
+pub fn hash_validator_answer(request_id: &str, validator: &AccountId, answer: &[AccountId], message: &str) -> String {
+    let mut concatenated_answer: Vec<u8> = Vec::new();
+
+    concatenated_answer.extend_from_slice(request_id.as_bytes());
+    concatenated_answer.extend_from_slice(validator.as_bytes());
+    answer.iter().for_each(|miner| concatenated_answer.extend_from_slice(miner.as_bytes()));
+    concatenated_answer.extend_from_slice(message.as_bytes());
+
+    hex::encode(env::keccak256(&concatenated_answer))
+}
+
 pub fn generate_validator_answer() -> Vec<AccountId> {
     let value = vec![
         "miner1.near".parse().unwrap(),
@@ -87,7 +150,7 @@ pub fn assert_log(event_name: &str, data: Vec<(&str, &str)>) {
 
     let expected_event = json!({
         "standard": "emip001",
-        "version": "1.0.0",
+        "version": "2.0.0",
         "event": event_name,
         "data": [data_map]
     });
@@ -114,7 +177,7 @@ pub fn assert_logs(expected_logs: Vec<Log>) {
 
                 let expected_event = json!({
                     "standard": "emip001",
-                    "version": "1.0.0",
+                    "version": "2.0.0",
                     "event": event_name,
                     "data": [data_map]
                 });
@@ -126,9 +189,136 @@ pub fn assert_logs(expected_logs: Vec<Log>) {
                 // Compare json objects
                 assert_eq!(log_event, expected_event);
             }
+            Log::EventBatch { event_name, data } => {
+                let data_maps: Vec<serde_json::Map<String, Value>> = data
+                    .iter()
+                    .map(|entry| entry.iter().map(|(key, value)| (key.to_string(), value.clone())).collect())
+                    .collect();
+
+                let expected_event = json!({
+                    "standard": "emip001",
+                    "version": "2.0.0",
+                    "event": event_name,
+                    "data": data_maps
+                });
+
+                let log_event: Value = serde_json::from_str(logs[i].trim_start_matches("EVENT_JSON:")).unwrap();
+
+                assert_eq!(log_event, expected_event);
+            }
             Log::Message(expected_text) => {
                 assert_eq!(logs[i], *expected_text);
             }
         }
     }
 }
+
+// @dev Transfers created by the last call, in order
+pub fn get_transfers() -> Vec<(AccountId, NearToken)> {
+    get_created_receipts()
+        .into_iter()
+        .flat_map(|receipt| {
+            receipt.actions.into_iter().filter_map(move |action| match action {
+                MockAction::Transfer { deposit, .. } => Some((receipt.receiver_id.clone(), deposit)),
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+// @dev Registers the default protocol and request, then the group of miners commits and reveals the default answer
+pub fn setup_request_with_revealed_miners(contract: &mut Contract) {
+    register_request(
+        contract,
+        RequestParams {
+            modules: vec![Module::TextPrompting, Module::ObjectRecognition],
+            ..RequestParams::default()
+        },
+    );
+
+    let registered_miners = group_registered_miners();
+    let default_answer_miners = default_miners_commit_answer();
+
+    for (index, miner) in registered_miners.clone().into_iter().enumerate() {
+        Environment::with_account(miner).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+        contract.register_miner(vec![Module::TextPrompting]);
+        contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), default_answer_miners[index].clone());
+    }
+
+    for miner in registered_miners {
+        Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
+        contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(true), "It's a cool NFT".to_string(), None);
+    }
+}
+
+// @dev Registers every validator and commits the given answer hash
+pub fn commit_by_validators(contract: &mut Contract, validators: &[(&str, &str)]) {
+    for (validator, answer) in validators {
+        let validator = get_account_for_validator(validator);
+
+        Environment::with_account(validator.clone())
+            .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+            .create();
+        contract.register_validator();
+
+        Environment::with_account(validator).with_block_timestamp(COMMIT_VALIDATOR_TIME).create();
+        contract.commit_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.to_string());
+    }
+}
+
+// @dev Every validator reveals the default validator answer
+pub fn reveal_by_validators(contract: &mut Contract, validators: &[&str]) {
+    for validator in validators {
+        Environment::with_account(get_account_for_validator(validator))
+            .with_block_timestamp(REVEAL_VALIDATOR_TIME)
+            .create();
+        contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), generate_validator_answer(), "It's a cool NFT".to_string(), None);
+    }
+}
+
+pub fn small_group_of_miners() -> Vec<AccountId> {
+    vec!["miner1.near".parse().unwrap(), "miner2.near".parse().unwrap(), "miner3.near".parse().unwrap()]
+}
+
+// @dev Registers the default request ranking three miners with the given scoring mode, the small group of miners reveals yes
+// and the default validator commits the given ranking. The environment is left at the reveal of the validators.
+pub fn setup_request_with_small_ranking(contract: &mut Contract, scoring: Option<ScoringMode>, ranking: &[AccountId]) {
+    register_request(
+        contract,
+        RequestParams {
+            ranking_size: Some(3),
+            scoring,
+            ..RequestParams::default()
+        },
+    );
+
+    for miner in small_group_of_miners() {
+        Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+        contract.register_miner(vec![Module::TextPrompting]);
+        contract.commit_by_miner(
+            DEFAULT_REQUEST_ID.to_string(),
+            hash_miner_answer(DEFAULT_REQUEST_ID, &miner, true, "It's a cool NFT"),
+        );
+    }
+
+    for miner in small_group_of_miners() {
+        Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
+        contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(true), "It's a cool NFT".to_string(), None);
+    }
+
+    let validator = get_default_validator_account();
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+
+    Environment::with_account(validator.clone())
+        .with_block_timestamp(COMMIT_VALIDATOR_TIME)
+        .create();
+    contract.commit_by_validator(
+        DEFAULT_REQUEST_ID.to_string(),
+        hash_validator_answer(DEFAULT_REQUEST_ID, &validator, ranking, "It's a cool NFT"),
+    );
+
+    Environment::with_account(validator).with_block_timestamp(REVEAL_VALIDATOR_TIME).create();
+}
@@ -0,0 +1,205 @@
+use serde_json::json;
+
+use common::constants::{
+    ACCOUNT_2, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, MINER_1, OWNER,
+    UNBONDING_TIME,
+};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{assert_logs, default_culture, get_account_for_protocol, get_default_miner_account, get_default_protocol_account, get_owner_account};
+
+use earthmind_rs::{Config, Contract, Module, Participant, Role, WithdrawStakeResult};
+
+pub mod common;
+
+#[test]
+fn test_owner_is_set_at_init() {
+    let contract = Contract::new(get_owner_account(), Config::default());
+
+    assert_eq!(contract.get_owner(), get_owner_account());
+    assert!(!contract.is_paused());
+    assert!(contract.has_role(Role::Pauser, get_owner_account()));
+}
+
+#[test]
+fn test_transfer_ownership() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let new_owner = get_account_for_protocol(ACCOUNT_2);
+
+    Environment::with_account(get_owner_account()).create();
+    contract.transfer_ownership(new_owner.clone());
+
+    assert_eq!(contract.get_owner(), new_owner);
+    assert!(!contract.has_role(Role::Admin, get_owner_account()));
+
+    assert_logs(vec![Log::Event {
+        event_name: "ownership_transferred".to_string(),
+        data: vec![("previous_owner", json![OWNER]), ("new_owner", json![ACCOUNT_2])],
+    }]);
+}
+
+#[test]
+#[should_panic(expected = "Only the owner can call this method")]
+fn test_transfer_ownership_when_caller_is_not_the_owner() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_miner_account()).create();
+    contract.transfer_ownership(get_default_miner_account());
+}
+
+#[test]
+fn test_grant_and_revoke_role() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let admin = get_account_for_protocol(ACCOUNT_2);
+    let pauser = get_default_miner_account();
+
+    Environment::with_account(get_owner_account()).create();
+    assert!(contract.grant_role(Role::Admin, admin.clone()));
+
+    // @dev Admins manage every role except the admin role
+    Environment::with_account(admin).create();
+    assert!(contract.grant_role(Role::Pauser, pauser.clone()));
+    assert!(!contract.grant_role(Role::Pauser, pauser.clone()));
+
+    assert!(contract.has_role(Role::Pauser, pauser.clone()));
+    assert!(!contract.has_role(Role::FeeManager, pauser.clone()));
+
+    assert!(contract.revoke_role(Role::Pauser, pauser.clone()));
+    assert!(!contract.has_role(Role::Pauser, pauser));
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "role_granted".to_string(),
+            data: vec![("role", json!["Pauser"]), ("account", json![MINER_1]), ("sender", json![ACCOUNT_2])],
+        },
+        Log::Message("Role already granted: miner1.near".to_string()),
+        Log::Event {
+            event_name: "role_revoked".to_string(),
+            data: vec![("role", json!["Pauser"]), ("account", json![MINER_1]), ("sender", json![ACCOUNT_2])],
+        },
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "Only the owner can call this method")]
+fn test_grant_admin_role_when_caller_is_admin() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let admin = get_account_for_protocol(ACCOUNT_2);
+
+    Environment::with_account(get_owner_account()).create();
+    contract.grant_role(Role::Admin, admin.clone());
+
+    Environment::with_account(admin).create();
+    contract.grant_role(Role::Admin, get_default_miner_account());
+}
+
+#[test]
+#[should_panic(expected = "Caller is missing the required role")]
+fn test_grant_role_when_caller_has_no_role() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_miner_account()).create();
+    contract.grant_role(Role::Pauser, get_default_miner_account());
+}
+
+#[test]
+fn test_pause_and_unpause_by_pauser() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let pauser = get_account_for_protocol(ACCOUNT_2);
+
+    Environment::with_account(get_owner_account()).create();
+    contract.grant_role(Role::Pauser, pauser.clone());
+
+    Environment::with_account(pauser).create();
+    contract.pause();
+    assert!(contract.is_paused());
+
+    contract.unpause();
+    assert!(!contract.is_paused());
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "pause".to_string(),
+            data: vec![("account", json![ACCOUNT_2])],
+        },
+        Log::Event {
+            event_name: "unpause".to_string(),
+            data: vec![("account", json![ACCOUNT_2])],
+        },
+    ]);
+
+    // @dev Registrations work again after unpausing
+    Environment::with_account(get_default_miner_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_MINER)
+        .create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    assert!(contract.is_miner_registered(get_default_miner_account()));
+}
+
+#[test]
+#[should_panic(expected = "Caller is missing the required role")]
+fn test_pause_when_caller_is_not_pauser() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_miner_account()).create();
+    contract.pause();
+}
+
+#[test]
+#[should_panic(expected = "Contract is paused")]
+fn test_register_miner_when_paused() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_owner_account()).create();
+    contract.pause();
+
+    Environment::with_account(get_default_miner_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_MINER)
+        .create();
+    contract.register_miner(vec![Module::TextPrompting]);
+}
+
+#[test]
+#[should_panic(expected = "Contract is paused")]
+fn test_commit_by_miner_when_paused() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(default_culture(), vec![Module::TextPrompting], None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    Environment::with_account(get_default_miner_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_MINER)
+        .create();
+    contract.register_miner(vec![Module::TextPrompting]);
+
+    Environment::with_account(get_owner_account()).create();
+    contract.pause();
+
+    Environment::with_account(get_default_miner_account()).create();
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+}
+
+#[test]
+fn test_withdraw_stake_when_paused() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let miner = get_default_miner_account();
+
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+
+    Environment::with_account(get_owner_account()).create();
+    contract.pause();
+
+    // @dev Unstaking and withdrawals stay available while paused
+    Environment::with_account(miner.clone()).create();
+    contract.request_unstake(Participant::Miner);
+
+    Environment::with_account(miner.clone()).with_block_timestamp(UNBONDING_TIME).create();
+    let result = contract.withdraw_stake(Participant::Miner);
+
+    assert_eq!(result, WithdrawStakeResult::Success);
+    assert!(!contract.is_miner_registered(miner));
+}
@@ -0,0 +1,132 @@
+use common::constants::{DEFAULT_DEPOSIT_MINER, DEFAULT_REQUEST_ID, REVEAL_MINER_TIME};
+use common::environment::Environment;
+use common::types::{Log, RequestParams};
+use common::utils::{assert_logs, get_default_miner_account, get_owner_account, register_request};
+
+use earthmind_rs::{hash_miner_commitment, Answer, AnswerKind, Config, Contract, Module, RevealError, RevealMinerResult};
+
+use serde_json::json;
+
+pub mod common;
+
+const SALT: &str = "6c2bf8a40d1e79b3a5f0e4c8d2b71a93";
+const MESSAGE: &str = "It's a cool NFT";
+
+// @dev Creates a request of the given kind and the default miner commits the given answer with a salt
+fn setup_miner_commit(contract: &mut Contract, answer_kind: AnswerKind, answer: &Answer) {
+    register_request(
+        contract,
+        RequestParams {
+            answer_kind: Some(answer_kind),
+            ..RequestParams::default()
+        },
+    );
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    contract.commit_by_miner(
+        DEFAULT_REQUEST_ID.to_string(),
+        hash_miner_commitment(DEFAULT_REQUEST_ID, miner.as_str(), answer.into(), MESSAGE, SALT),
+    );
+
+    Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
+}
+
+#[test]
+fn test_reveal_by_miner_with_score() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let answer = Answer::Score(-42);
+    setup_miner_commit(&mut contract, AnswerKind::Score { min: -100, max: 100 }, &answer);
+
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, MESSAGE.to_string(), Some(SALT.to_string()));
+
+    assert_eq!(result, RevealMinerResult::Success);
+    assert_logs(vec![Log::Event {
+        event_name: "reveal_miner".to_string(),
+        data: vec![
+            ("request_id", json![DEFAULT_REQUEST_ID]),
+            ("answer", json![{ "Score": -42 }]),
+            ("message", json![MESSAGE]),
+        ],
+    }]);
+
+    let proposal = contract
+        .get_miner_proposal(DEFAULT_REQUEST_ID.to_string(), get_default_miner_account())
+        .unwrap();
+    assert_eq!(proposal.answer, Some(Answer::Score(-42)));
+}
+
+#[test]
+fn test_reveal_by_miner_when_answer_is_of_another_kind() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let answer = Answer::YesNo(true);
+    setup_miner_commit(&mut contract, AnswerKind::MultipleChoice { options: 3 }, &answer);
+
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, MESSAGE.to_string(), Some(SALT.to_string()));
+
+    assert_eq!(result, RevealMinerResult::Fail(RevealError::InvalidAnswer));
+    assert_logs(vec![Log::Message("Answer doesn't match the kind of the request".to_string())]);
+}
+
+#[test]
+fn test_reveal_by_miner_when_choice_is_out_of_range() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let answer = Answer::Choice(3);
+    setup_miner_commit(&mut contract, AnswerKind::MultipleChoice { options: 3 }, &answer);
+
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, MESSAGE.to_string(), Some(SALT.to_string()));
+
+    assert_eq!(result, RevealMinerResult::Fail(RevealError::InvalidAnswer));
+}
+
+#[test]
+fn test_reveal_by_miner_when_content_is_not_a_hash() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let answer = Answer::Content("not a hash".to_string());
+    setup_miner_commit(&mut contract, AnswerKind::Content, &answer);
+
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, MESSAGE.to_string(), Some(SALT.to_string()));
+
+    assert_eq!(result, RevealMinerResult::Fail(RevealError::InvalidAnswer));
+}
+
+#[test]
+fn test_reveal_by_miner_without_salt_when_answer_is_not_yes_or_no() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let answer = Answer::Choice(1);
+    setup_miner_commit(&mut contract, AnswerKind::MultipleChoice { options: 3 }, &answer);
+
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, MESSAGE.to_string(), None);
+
+    assert_eq!(result, RevealMinerResult::Fail(RevealError::InvalidAnswer));
+    assert_logs(vec![Log::Message("Legacy commitments only support yes or no answers".to_string())]);
+}
+
+#[test]
+#[should_panic(expected = "Invalid answer kind")]
+fn test_request_governance_decision_with_a_single_option() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    register_request(
+        &mut contract,
+        RequestParams {
+            answer_kind: Some(AnswerKind::MultipleChoice { options: 1 }),
+            ..RequestParams::default()
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid answer kind")]
+fn test_request_governance_decision_with_empty_score_range() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    register_request(
+        &mut contract,
+        RequestParams {
+            answer_kind: Some(AnswerKind::Score { min: 10, max: 10 }),
+            ..RequestParams::default()
+        },
+    );
+}
@@ -1,20 +1,18 @@
 use near_sdk::test_utils::get_logs;
 use serde_json::json;
 
-use common::constants::{
-    DEFAULT_CULTURE, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, MINER_1,
-};
+use common::constants::{DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, MINER_1};
 use common::environment::Environment;
 use common::types::Log;
-use common::utils::{assert_logs, get_default_miner_account, get_default_protocol_account};
+use common::utils::{assert_logs, default_culture, get_default_miner_account, get_default_protocol_account, get_owner_account};
 
-use earthmind_rs::{CommitMinerResult, Contract, Module};
+use earthmind_rs::{CommitError, CommitMinerResult, Config, Contract, Module};
 
 pub mod common;
 
 #[test]
 fn test_commit_by_miner_when_miner_and_request_exist() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -23,8 +21,8 @@ fn test_commit_by_miner_when_miner_and_request_exist() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -32,14 +30,18 @@ fn test_commit_by_miner_when_miner_and_request_exist() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
     // @dev Miner register to earthmind protocol
     let miner = get_default_miner_account();
     Environment::with_account(miner).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
-    contract.register_miner();
+    contract.register_miner(vec![Module::TextPrompting]);
 
     let result = contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
 
@@ -48,7 +50,7 @@ fn test_commit_by_miner_when_miner_and_request_exist() {
     assert_logs(vec![
         Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![MINER_1])],
+            data: vec![("miner", json![MINER_1]), ("modules", json![["TextPrompting"]])],
         },
         Log::Event {
             event_name: "commit_miner".to_string(),
@@ -59,7 +61,7 @@ fn test_commit_by_miner_when_miner_and_request_exist() {
 
 #[test]
 fn test_commit_by_miner_when_miner_dont_registered_and_request_exist() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -68,8 +70,8 @@ fn test_commit_by_miner_when_miner_dont_registered_and_request_exist() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -77,7 +79,11 @@ fn test_commit_by_miner_when_miner_dont_registered_and_request_exist() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -86,7 +92,7 @@ fn test_commit_by_miner_when_miner_dont_registered_and_request_exist() {
 
     let result = contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
 
-    assert_eq!(result, CommitMinerResult::Fail);
+    assert_eq!(result, CommitMinerResult::Fail(CommitError::NotRegistered));
 
     let logs = get_logs();
     assert_eq!(logs.len(), 1);
@@ -95,7 +101,7 @@ fn test_commit_by_miner_when_miner_dont_registered_and_request_exist() {
 
 #[test]
 fn test_commit_by_miner_when_miner_registered_and_request_dont_exist() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -104,7 +110,7 @@ fn test_commit_by_miner_when_miner_registered_and_request_dont_exist() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
+    contract.register_protocol(default_culture(), modules, None);
 
     assert_logs(vec![Log::Event {
         event_name: "register_protocol".to_string(),
@@ -114,16 +120,16 @@ fn test_commit_by_miner_when_miner_registered_and_request_dont_exist() {
     let miner = get_default_miner_account();
     Environment::with_account(miner).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
 
-    contract.register_miner();
+    contract.register_miner(vec![Module::TextPrompting]);
 
     let result = contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
 
-    assert_eq!(result, CommitMinerResult::Fail);
+    assert_eq!(result, CommitMinerResult::Fail(CommitError::RequestNotFound));
 
     assert_logs(vec![
         Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![MINER_1])],
+            data: vec![("miner", json![MINER_1]), ("modules", json![["TextPrompting"]])],
         },
         Log::Message("Request is not registered: 73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b".to_string()),
     ]);
@@ -131,7 +137,7 @@ fn test_commit_by_miner_when_miner_registered_and_request_dont_exist() {
 
 #[test]
 fn test_commit_by_miner_when_miner_and_request_exist_and_commit_already() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -140,8 +146,8 @@ fn test_commit_by_miner_when_miner_and_request_exist_and_commit_already() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -149,25 +155,29 @@ fn test_commit_by_miner_when_miner_and_request_exist_and_commit_already() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
     let miner = get_default_miner_account();
     Environment::with_account(miner).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
 
-    contract.register_miner();
+    contract.register_miner(vec![Module::TextPrompting]);
 
     contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
 
     let result = contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
 
-    assert_eq!(result, CommitMinerResult::Fail);
+    assert_eq!(result, CommitMinerResult::Fail(CommitError::AlreadyCommitted));
 
     assert_logs(vec![
         Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![MINER_1])],
+            data: vec![("miner", json![MINER_1]), ("modules", json![["TextPrompting"]])],
         },
         Log::Event {
             event_name: "commit_miner".to_string(),
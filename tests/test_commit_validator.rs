@@ -1,20 +1,20 @@
 use serde_json::json;
 
 use common::constants::{
-    COMMIT_VALIDATOR_TIME, DEFAULT_CULTURE, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_REQUEST_ID,
-    DEFAULT_VALIDATOR_ANSWER, VALIDATOR_1,
+    COMMIT_VALIDATOR_TIME, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER,
+    VALIDATOR_1,
 };
 use common::environment::Environment;
 use common::types::Log;
-use common::utils::{assert_logs, get_default_protocol_account, get_default_validator_account};
+use common::utils::{assert_logs, default_culture, get_default_protocol_account, get_default_validator_account, get_owner_account};
 
-use earthmind_rs::{CommitValidatorResult, Contract, Module};
+use earthmind_rs::{CommitError, CommitValidatorResult, Config, Contract, Module};
 
 pub mod common;
 
 #[test]
 fn test_commit_by_validator_when_validator_and_request_exist() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -23,8 +23,8 @@ fn test_commit_by_validator_when_validator_and_request_exist() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
 
     assert_logs(vec![
         Log::Event {
@@ -33,7 +33,11 @@ fn test_commit_by_validator_when_validator_and_request_exist() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -69,7 +73,7 @@ fn test_commit_by_validator_when_validator_and_request_exist() {
 #[test]
 #[should_panic]
 fn test_commit_by_validator_when_validator_dont_registered_and_request_exist() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -78,8 +82,8 @@ fn test_commit_by_validator_when_validator_dont_registered_and_request_exist() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
 
     assert_logs(vec![
         Log::Event {
@@ -88,20 +92,24 @@ fn test_commit_by_validator_when_validator_dont_registered_and_request_exist() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
     let result = contract.commit_by_validator(DEFAULT_REQUEST_ID.to_string(), DEFAULT_VALIDATOR_ANSWER.to_string());
 
-    assert_eq!(result, CommitValidatorResult::Fail);
+    assert_eq!(result, CommitValidatorResult::Fail(CommitError::NotRegistered));
 
     assert_logs(vec![Log::Message("Validator is not registered: validator1.near".to_string())]);
 }
 
 #[test]
 fn test_commit_by_validator_when_validator_registered_and_request_dont_exist() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     let validator = get_default_validator_account();
     Environment::with_account(validator).with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR).create();
@@ -110,7 +118,7 @@ fn test_commit_by_validator_when_validator_registered_and_request_dont_exist() {
 
     let result = contract.commit_by_validator(DEFAULT_REQUEST_ID.to_string(), DEFAULT_VALIDATOR_ANSWER.to_string());
 
-    assert_eq!(result, CommitValidatorResult::Fail);
+    assert_eq!(result, CommitValidatorResult::Fail(CommitError::RequestNotFound));
 
     assert_logs(vec![
         Log::Event {
@@ -123,7 +131,7 @@ fn test_commit_by_validator_when_validator_registered_and_request_dont_exist() {
 
 #[test]
 fn test_commit_by_validator_when_validator_and_request_exist_and_commit_already() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -132,8 +140,8 @@ fn test_commit_by_validator_when_validator_and_request_exist_and_commit_already(
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
 
     assert_logs(vec![
         Log::Event {
@@ -142,7 +150,11 @@ fn test_commit_by_validator_when_validator_and_request_exist_and_commit_already(
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -168,7 +180,7 @@ fn test_commit_by_validator_when_validator_and_request_exist_and_commit_already(
     contract.commit_by_validator(DEFAULT_REQUEST_ID.to_string(), DEFAULT_VALIDATOR_ANSWER.to_string());
     let result = contract.commit_by_validator(DEFAULT_REQUEST_ID.to_string(), DEFAULT_VALIDATOR_ANSWER.to_string());
 
-    assert_eq!(result, CommitValidatorResult::Fail);
+    assert_eq!(result, CommitValidatorResult::Fail(CommitError::AlreadyCommitted));
 
     assert_logs(vec![
         Log::Event {
@@ -0,0 +1,202 @@
+use near_sdk::AccountId;
+
+use common::constants::{
+    COMMIT_VALIDATOR_TIME, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_REQUEST_ID, REVEAL_MINER_TIME, REVEAL_VALIDATOR_TIME,
+};
+use common::environment::Environment;
+use common::types::RequestParams;
+use common::utils::{
+    generate_validator_answer, get_default_miner_account, get_default_validator_account, get_owner_account, hash_miner_answer, hash_validator_answer,
+    register_request, setup_request_with_revealed_miners,
+};
+
+use earthmind_rs::{
+    hash_legacy_miner_answer, hash_legacy_validator_answer, hash_miner_commitment, hash_validator_commitment, Answer, AnswerRef, CommitMinerResult, Config,
+    Contract, Module, RevealError, RevealMinerResult, RevealValidatorResult,
+};
+
+pub mod common;
+
+const SALT: &str = "6c2bf8a40d1e79b3a5f0e4c8d2b71a93";
+const MESSAGE: &str = "It's a cool NFT";
+
+// @dev Registers the default protocol, request and miner, then the miner commits the given hash
+fn setup_miner_commit(contract: &mut Contract, commitment: String) {
+    register_request(contract, RequestParams::default());
+
+    Environment::with_account(get_default_miner_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_MINER)
+        .create();
+    contract.register_miner(vec![Module::TextPrompting]);
+
+    let result = contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), commitment);
+    assert_eq!(result, CommitMinerResult::Success);
+
+    Environment::with_account(get_default_miner_account())
+        .with_block_timestamp(REVEAL_MINER_TIME)
+        .create();
+}
+
+#[test]
+fn test_miner_commitment_format() {
+    let miner = get_default_miner_account();
+
+    // @dev Off-chain clients must produce exactly this hash
+    assert_eq!(
+        hash_miner_commitment(DEFAULT_REQUEST_ID, miner.as_str(), AnswerRef::YesNo(true), MESSAGE, SALT),
+        "168838b965f079cd31618c09b22a746e91942e65b9b2a9b5568d50afcab5137e"
+    );
+    assert_ne!(
+        hash_miner_commitment(DEFAULT_REQUEST_ID, miner.as_str(), AnswerRef::YesNo(true), MESSAGE, SALT),
+        hash_miner_commitment(DEFAULT_REQUEST_ID, miner.as_str(), AnswerRef::YesNo(false), MESSAGE, SALT)
+    );
+}
+
+#[test]
+fn test_miner_commitment_is_bound_to_the_answer_kind() {
+    let miner = get_default_miner_account();
+    let hashes: Vec<String> = [AnswerRef::YesNo(true), AnswerRef::Choice(1), AnswerRef::Score(1)]
+        .into_iter()
+        .map(|answer| hash_miner_commitment(DEFAULT_REQUEST_ID, miner.as_str(), answer, MESSAGE, SALT))
+        .collect();
+
+    assert_ne!(hashes[0], hashes[1]);
+    assert_ne!(hashes[1], hashes[2]);
+    assert_ne!(hashes[0], hashes[2]);
+}
+
+#[test]
+fn test_legacy_hashes_match_the_host_keccak() {
+    let miner = get_default_miner_account();
+    let validator = get_default_validator_account();
+    let answer = generate_validator_answer();
+
+    // @dev The shared module hashes without the host functions, it must agree with `env::keccak256`
+    assert_eq!(
+        hash_legacy_miner_answer(DEFAULT_REQUEST_ID, miner.as_str(), true, MESSAGE),
+        hash_miner_answer(DEFAULT_REQUEST_ID, &miner, true, MESSAGE)
+    );
+    assert_eq!(
+        hash_legacy_validator_answer(DEFAULT_REQUEST_ID, validator.as_str(), &answer, MESSAGE),
+        hash_validator_answer(DEFAULT_REQUEST_ID, &validator, &answer, MESSAGE)
+    );
+}
+
+#[test]
+fn test_validator_commitment_is_unambiguous() {
+    let validator = get_default_validator_account();
+    let answer: Vec<AccountId> = vec!["abc.near".parse().unwrap(), "de.near".parse().unwrap()];
+    let shifted: Vec<AccountId> = vec!["abc.ne".parse().unwrap(), "arde.near".parse().unwrap()];
+
+    // @dev Without separators both answers concatenate to the same bytes
+    assert_eq!(
+        hash_legacy_validator_answer(DEFAULT_REQUEST_ID, validator.as_str(), &answer, MESSAGE),
+        hash_legacy_validator_answer(DEFAULT_REQUEST_ID, validator.as_str(), &shifted, MESSAGE)
+    );
+    assert_ne!(
+        hash_validator_commitment(DEFAULT_REQUEST_ID, validator.as_str(), &answer, MESSAGE, SALT),
+        hash_validator_commitment(DEFAULT_REQUEST_ID, validator.as_str(), &shifted, MESSAGE, SALT)
+    );
+}
+
+#[test]
+fn test_reveal_by_miner_with_salt() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let miner = get_default_miner_account();
+
+    setup_miner_commit(
+        &mut contract,
+        hash_miner_commitment(DEFAULT_REQUEST_ID, miner.as_str(), AnswerRef::YesNo(true), MESSAGE, SALT),
+    );
+
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(true), MESSAGE.to_string(), Some(SALT.to_string()));
+
+    assert_eq!(result, RevealMinerResult::Success);
+    assert!(contract.get_miner_proposal(DEFAULT_REQUEST_ID.to_string(), miner).unwrap().is_revealed);
+}
+
+#[test]
+fn test_reveal_by_miner_with_wrong_salt() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let miner = get_default_miner_account();
+
+    setup_miner_commit(
+        &mut contract,
+        hash_miner_commitment(DEFAULT_REQUEST_ID, miner.as_str(), AnswerRef::YesNo(true), MESSAGE, SALT),
+    );
+
+    let wrong_salt = SALT.replace('6', "7");
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(true), MESSAGE.to_string(), Some(wrong_salt));
+
+    assert_eq!(result, RevealMinerResult::Fail(RevealError::HashMismatch));
+}
+
+#[test]
+fn test_reveal_by_miner_with_short_salt() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let miner = get_default_miner_account();
+
+    setup_miner_commit(
+        &mut contract,
+        hash_miner_commitment(DEFAULT_REQUEST_ID, miner.as_str(), AnswerRef::YesNo(true), MESSAGE, "1234"),
+    );
+
+    let result = contract.reveal_by_miner(
+        DEFAULT_REQUEST_ID.to_string(),
+        Answer::YesNo(true),
+        MESSAGE.to_string(),
+        Some("1234".to_string()),
+    );
+
+    assert_eq!(result, RevealMinerResult::Fail(RevealError::InvalidSalt));
+}
+
+#[test]
+fn test_reveal_by_miner_without_salt_when_legacy_is_disabled() {
+    let mut contract = Contract::new(
+        get_owner_account(),
+        Config {
+            accept_legacy_commitments: false,
+            ..Config::default()
+        },
+    );
+    let miner = get_default_miner_account();
+
+    setup_miner_commit(
+        &mut contract,
+        hash_miner_commitment(DEFAULT_REQUEST_ID, miner.as_str(), AnswerRef::YesNo(true), MESSAGE, SALT),
+    );
+
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(true), MESSAGE.to_string(), None);
+    assert_eq!(result, RevealMinerResult::Fail(RevealError::LegacyCommitmentDisabled));
+
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(true), MESSAGE.to_string(), Some(SALT.to_string()));
+    assert_eq!(result, RevealMinerResult::Success);
+}
+
+#[test]
+fn test_reveal_by_validator_with_salt() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_revealed_miners(&mut contract);
+
+    let validator = get_default_validator_account();
+    let answer = generate_validator_answer();
+
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+
+    Environment::with_account(validator.clone())
+        .with_block_timestamp(COMMIT_VALIDATOR_TIME)
+        .create();
+    contract.commit_by_validator(
+        DEFAULT_REQUEST_ID.to_string(),
+        hash_validator_commitment(DEFAULT_REQUEST_ID, validator.as_str(), &answer, MESSAGE, SALT),
+    );
+
+    Environment::with_account(validator).with_block_timestamp(REVEAL_VALIDATOR_TIME).create();
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer, MESSAGE.to_string(), Some(SALT.to_string()));
+
+    assert_eq!(result, RevealValidatorResult::Success);
+}
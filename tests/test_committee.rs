@@ -0,0 +1,245 @@
+use near_sdk::{AccountId, NearToken};
+
+use common::constants::{
+    COMMIT_VALIDATOR_TIME, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER,
+};
+use common::environment::Environment;
+use common::types::{Log, RequestParams};
+use common::utils::{assert_logs, get_account_for_miner, get_account_for_validator, get_owner_account, register_request, small_group_of_miners};
+
+use earthmind_rs::{CommitError, CommitMinerResult, CommitValidatorResult, CommitteeConfig, Config, Contract, Module, Participant, MAX_COMMITTEE_CANDIDATES};
+
+pub mod common;
+
+fn validators() -> Vec<AccountId> {
+    vec![get_account_for_validator("validator1.near"), get_account_for_validator("validator2.near")]
+}
+
+fn contract_with_committee(miners: u32, validators: u32, stake_weighted: bool) -> Contract {
+    Contract::new(
+        get_owner_account(),
+        Config {
+            max_ranking_size: miners,
+            committee: Some(CommitteeConfig {
+                miners,
+                validators,
+                stake_weighted,
+            }),
+            ..Config::default()
+        },
+    )
+}
+
+fn register_participants(contract: &mut Contract) {
+    for miner in small_group_of_miners() {
+        Environment::with_account(miner).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+        contract.register_miner(vec![Module::TextPrompting]);
+    }
+
+    for validator in validators() {
+        Environment::with_account(validator).with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR).create();
+        contract.register_validator();
+    }
+}
+
+fn sorted(mut accounts: Vec<AccountId>) -> Vec<AccountId> {
+    accounts.sort();
+    accounts
+}
+
+#[test]
+fn test_request_governance_decision_samples_a_committee() {
+    let mut contract = contract_with_committee(2, 1, false);
+    register_participants(&mut contract);
+    register_request(&mut contract, RequestParams::default());
+
+    let committee = contract.get_committee(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(committee.miners.len(), 2);
+    assert_ne!(committee.miners[0], committee.miners[1]);
+    assert!(committee.miners.iter().all(|miner| small_group_of_miners().contains(miner)));
+    assert_eq!(committee.validators.len(), 1);
+    assert!(validators().contains(&committee.validators[0]));
+
+    for miner in small_group_of_miners() {
+        assert_eq!(
+            contract.is_selected(DEFAULT_REQUEST_ID.to_string(), Participant::Miner, miner.clone()),
+            committee.miners.contains(&miner)
+        );
+    }
+}
+
+#[test]
+fn test_committee_takes_every_eligible_account_when_there_are_not_enough() {
+    let mut contract = contract_with_committee(5, 5, true);
+    register_participants(&mut contract);
+    register_request(&mut contract, RequestParams::default());
+
+    let committee = contract.get_committee(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(sorted(committee.miners), small_group_of_miners());
+    assert_eq!(sorted(committee.validators), validators());
+}
+
+#[test]
+fn test_committee_among_more_accounts_than_the_candidates_read() {
+    let mut contract = contract_with_committee(2, 5, false);
+    register_participants(&mut contract);
+
+    let validators: Vec<AccountId> = (0..MAX_COMMITTEE_CANDIDATES + 50)
+        .map(|index| get_account_for_validator(&format!("validator{}.near", index + 3)))
+        .collect();
+    for validator in &validators {
+        Environment::with_account(validator.clone())
+            .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+            .create();
+        contract.register_validator();
+    }
+    register_request(&mut contract, RequestParams::default());
+
+    let committee = contract.get_committee(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    let mut selected = sorted(committee.validators);
+    selected.dedup();
+
+    assert_eq!(selected.len(), 5);
+    assert!(selected.iter().all(|validator| contract.is_validator_registered(validator.clone())));
+}
+
+#[test]
+fn test_committee_is_not_drawn_from_one_run_of_accounts() {
+    let mut contract = contract_with_committee(2, 20, false);
+    register_participants(&mut contract);
+
+    let validators: Vec<AccountId> = (0..MAX_COMMITTEE_CANDIDATES + 200)
+        .map(|index| get_account_for_validator(&format!("validator{}.near", index + 3)))
+        .collect();
+    for validator in &validators {
+        Environment::with_account(validator.clone())
+            .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+            .create();
+        contract.register_validator();
+    }
+    register_request(&mut contract, RequestParams::default());
+
+    // @dev Registration order of the selected validators, the two first validators are registered before the others
+    let registered = contract.get_validators(None, Some(2));
+    let mut positions: Vec<usize> = contract
+        .get_committee(DEFAULT_REQUEST_ID.to_string())
+        .unwrap()
+        .validators
+        .iter()
+        .map(|validator| registered.iter().chain(&validators).position(|account| account == validator).unwrap())
+        .collect();
+    positions.sort_unstable();
+
+    // @dev The smallest run of registrations, wrapping around, that holds every selected validator
+    let accounts = validators.len() + registered.len();
+    let largest_gap = positions
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .chain([positions[0] + accounts - positions[positions.len() - 1]])
+        .max()
+        .unwrap();
+
+    assert_eq!(positions.len(), 20);
+    assert!(accounts - largest_gap + 1 > MAX_COMMITTEE_CANDIDATES as usize);
+}
+
+#[test]
+fn test_committee_skips_unbonding_accounts() {
+    let mut contract = contract_with_committee(3, 2, false);
+    register_participants(&mut contract);
+
+    Environment::with_account(get_account_for_miner("miner2.near")).create();
+    contract.request_unstake(Participant::Miner);
+
+    register_request(&mut contract, RequestParams::default());
+
+    let committee = contract.get_committee(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(
+        sorted(committee.miners),
+        vec![get_account_for_miner("miner1.near"), get_account_for_miner("miner3.near")]
+    );
+}
+
+#[test]
+fn test_stake_weighted_committee_favours_the_largest_stake() {
+    let mut contract = contract_with_committee(1, 1, true);
+    register_participants(&mut contract);
+
+    Environment::with_account(get_account_for_miner("miner2.near"))
+        .with_attached_deposit(NearToken::from_near(10_000))
+        .create();
+    contract.add_stake(Participant::Miner);
+
+    register_request(&mut contract, RequestParams::default());
+
+    let committee = contract.get_committee(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(committee.miners, vec![get_account_for_miner("miner2.near")]);
+}
+
+#[test]
+fn test_commit_by_miner_outside_the_committee() {
+    let mut contract = contract_with_committee(2, 1, false);
+    register_participants(&mut contract);
+    register_request(&mut contract, RequestParams::default());
+
+    let committee = contract.get_committee(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    let outsider = small_group_of_miners().into_iter().find(|miner| !committee.miners.contains(miner)).unwrap();
+
+    Environment::with_account(outsider.clone()).create();
+    let result = contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+
+    assert_eq!(result, CommitMinerResult::Fail(CommitError::NotSelected));
+    assert_logs(vec![Log::Message(format!("Miner not selected: {}", outsider))]);
+
+    Environment::with_account(committee.miners[0].clone()).create();
+    let result = contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+
+    assert_eq!(result, CommitMinerResult::Success);
+}
+
+#[test]
+fn test_commit_by_validator_outside_the_committee() {
+    let mut contract = contract_with_committee(2, 1, false);
+    register_participants(&mut contract);
+    register_request(&mut contract, RequestParams::default());
+
+    let committee = contract.get_committee(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    let outsider = validators().into_iter().find(|validator| !committee.validators.contains(validator)).unwrap();
+
+    Environment::with_account(outsider.clone()).with_block_timestamp(COMMIT_VALIDATOR_TIME).create();
+    let result = contract.commit_by_validator(DEFAULT_REQUEST_ID.to_string(), DEFAULT_VALIDATOR_ANSWER.to_string());
+
+    assert_eq!(result, CommitValidatorResult::Fail(CommitError::NotSelected));
+    assert_logs(vec![Log::Message(format!("Validator not selected: {}", outsider))]);
+    assert!(!contract.is_selected(DEFAULT_REQUEST_ID.to_string(), Participant::Validator, outsider));
+}
+
+#[test]
+fn test_requests_without_a_committee_accept_every_account() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_request(&mut contract, RequestParams::default());
+
+    assert_eq!(contract.get_committee(DEFAULT_REQUEST_ID.to_string()), None);
+    assert!(contract.is_selected(DEFAULT_REQUEST_ID.to_string(), Participant::Miner, get_account_for_miner("miner1.near")));
+    assert!(!contract.is_selected("unknown".to_string(), Participant::Miner, get_account_for_miner("miner1.near")));
+}
+
+#[test]
+#[should_panic(expected = "Config value out of bounds")]
+fn test_committee_smaller_than_the_largest_ranking() {
+    Contract::new(
+        get_owner_account(),
+        Config {
+            committee: Some(CommitteeConfig {
+                miners: 5,
+                validators: 5,
+                stake_weighted: false,
+            }),
+            ..Config::default()
+        },
+    );
+}
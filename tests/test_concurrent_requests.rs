@@ -0,0 +1,116 @@
+use near_sdk::AccountId;
+
+use common::constants::{
+    COMMIT_VALIDATOR_TIME, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MESSAGE_TO_REQUEST, REVEAL_MINER_TIME,
+    REVEAL_TOPTEN_TIME, REVEAL_VALIDATOR_TIME,
+};
+use common::environment::Environment;
+use common::utils::{
+    default_culture, generate_validator_answer, get_default_protocol_account, get_default_validator_account, get_owner_account, get_request_id,
+    group_registered_miners, hash_miner_answer, hash_validator_answer,
+};
+
+use earthmind_rs::{Answer, CommitMinerResult, CommitValidatorResult, Config, Contract, Module, RevealError, RevealMinerResult, RevealValidatorResult};
+
+pub mod common;
+
+const SECOND_MESSAGE_TO_REQUEST: &str = "Should we remove this old NFT from our protocol?";
+const MINER_MESSAGE: &str = "It's a cool NFT";
+const VALIDATOR_MESSAGE: &str = "It's a cool NFT";
+
+// @dev Registers the default protocol and two requests that run at the same time
+fn setup_concurrent_requests(contract: &mut Contract) -> (String, String) {
+    let protocol = get_default_protocol_account();
+    Environment::with_account(protocol.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+    contract.request_governance_decision(SECOND_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    (
+        get_request_id(&protocol, DEFAULT_MESSAGE_TO_REQUEST, 0),
+        get_request_id(&protocol, SECOND_MESSAGE_TO_REQUEST, 1),
+    )
+}
+
+fn commit_miners(contract: &mut Contract, miners: &[AccountId], request_ids: &[&String]) {
+    for miner in miners {
+        Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+        contract.register_miner(vec![Module::TextPrompting]);
+
+        for request_id in request_ids {
+            let answer = hash_miner_answer(request_id, miner, true, MINER_MESSAGE);
+            let result = contract.commit_by_miner(request_id.to_string(), answer);
+
+            assert_eq!(result, CommitMinerResult::Success);
+        }
+    }
+}
+
+#[test]
+fn test_miner_commits_on_two_concurrent_requests() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let (first_request_id, second_request_id) = setup_concurrent_requests(&mut contract);
+
+    commit_miners(&mut contract, &group_registered_miners(), &[&first_request_id, &second_request_id]);
+}
+
+#[test]
+fn test_reveals_and_votes_are_kept_per_request() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let (first_request_id, second_request_id) = setup_concurrent_requests(&mut contract);
+
+    let miners = group_registered_miners();
+    commit_miners(&mut contract, &miners, &[&first_request_id, &second_request_id]);
+
+    // @dev The miners only reveal on the first request
+    for miner in &miners {
+        Environment::with_account(miner.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
+
+        let result = contract.reveal_by_miner(first_request_id.clone(), Answer::YesNo(true), MINER_MESSAGE.to_string(), None);
+        assert_eq!(result, RevealMinerResult::Success);
+    }
+
+    let validator = get_default_validator_account();
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+
+    Environment::with_account(validator.clone())
+        .with_block_timestamp(COMMIT_VALIDATOR_TIME)
+        .create();
+
+    let answer = hash_validator_answer(&first_request_id, &validator, &generate_validator_answer(), VALIDATOR_MESSAGE);
+    let result = contract.commit_by_validator(first_request_id.clone(), answer);
+    assert_eq!(result, CommitValidatorResult::Success);
+
+    let answer = hash_validator_answer(&second_request_id, &validator, &generate_validator_answer(), VALIDATOR_MESSAGE);
+    let result = contract.commit_by_validator(second_request_id.clone(), answer);
+    assert_eq!(result, CommitValidatorResult::Success);
+
+    Environment::with_account(validator).with_block_timestamp(REVEAL_VALIDATOR_TIME).create();
+
+    let result = contract.reveal_by_validator(first_request_id.clone(), generate_validator_answer(), VALIDATOR_MESSAGE.to_string(), None);
+    assert_eq!(result, RevealValidatorResult::Success);
+
+    // @dev The reveals of the first request must not be visible from the second one
+    let result = contract.reveal_by_validator(second_request_id.clone(), generate_validator_answer(), VALIDATOR_MESSAGE.to_string(), None);
+    assert_eq!(result, RevealValidatorResult::Fail(RevealError::MinerNotRevealed));
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    let first_top_miners = contract.get_top_voters(first_request_id);
+    let second_top_miners = contract.get_top_voters(second_request_id);
+
+    // @dev Every miner has one vote, ties are ranked by account id
+    let mut expected: Vec<_> = miners.into_iter().map(|miner| (miner, 1)).collect();
+    expected.sort();
+    assert_eq!(first_top_miners, expected);
+    assert!(second_top_miners.is_empty());
+}
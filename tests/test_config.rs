@@ -0,0 +1,293 @@
+use near_sdk::NearToken;
+use serde_json::json;
+
+use common::constants::{
+    ACCOUNT_2, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER,
+    REVEAL_TOPTEN_TIME, VALIDATOR_1, VALIDATOR_2, VALIDATOR_2_ANSWER,
+};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{
+    assert_logs, commit_by_validators, default_culture, get_account_for_miner, get_account_for_protocol, get_account_for_validator, get_default_miner_account,
+    get_default_protocol_account, get_owner_account, reveal_by_validators, setup_request_with_revealed_miners,
+};
+
+use earthmind_rs::{CommitMinerResult, Config, Contract, Module, Role};
+
+pub mod common;
+
+fn raised_miner_stake() -> Config {
+    Config {
+        min_miner_stake: NearToken::from_near(2),
+        ..Config::default()
+    }
+}
+
+#[test]
+fn test_get_config_after_init() {
+    let contract = Contract::new(get_owner_account(), Config::default());
+
+    let config = contract.get_config();
+
+    assert_eq!(config.min_miner_stake, NearToken::from_near(1));
+    assert_eq!(config.min_validator_stake, NearToken::from_near(10));
+    assert_eq!(config.protocol_registration_fee, NearToken::from_near(5));
+}
+
+#[test]
+#[should_panic(expected = "Config value out of bounds")]
+fn test_init_with_config_out_of_bounds() {
+    Contract::new(
+        get_owner_account(),
+        Config {
+            min_validator_stake: NearToken::from_yoctonear(0),
+            ..Config::default()
+        },
+    );
+}
+
+#[test]
+fn test_update_config_by_admin() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let admin = get_account_for_protocol(ACCOUNT_2);
+
+    Environment::with_account(get_owner_account()).create();
+    contract.grant_role(Role::Admin, admin.clone());
+
+    Environment::with_account(admin).create();
+    contract.update_config(raised_miner_stake());
+
+    assert_eq!(contract.get_config(), raised_miner_stake());
+
+    assert_logs(vec![Log::Event {
+        event_name: "config_updated".to_string(),
+        data: vec![
+            ("sender", json![ACCOUNT_2]),
+            ("previous", json!(Config::default())),
+            ("config", json!(raised_miner_stake())),
+        ],
+    }]);
+}
+
+#[test]
+#[should_panic(expected = "Caller is missing the required role")]
+fn test_update_config_when_caller_is_not_admin() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_miner_account()).create();
+    contract.update_config(raised_miner_stake());
+}
+
+#[test]
+fn test_update_config_by_fee_manager() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let fee_manager = get_account_for_protocol(ACCOUNT_2);
+
+    Environment::with_account(get_owner_account()).create();
+    contract.grant_role(Role::FeeManager, fee_manager.clone());
+
+    Environment::with_account(fee_manager).create();
+    contract.update_config(Config {
+        protocol_registration_fee: NearToken::from_near(7),
+        min_request_fee: NearToken::from_near(3),
+        ..raised_miner_stake()
+    });
+
+    assert_eq!(contract.get_config().min_miner_stake, NearToken::from_near(2));
+    assert_eq!(contract.get_config().protocol_registration_fee, NearToken::from_near(7));
+    assert_eq!(contract.get_config().min_request_fee, NearToken::from_near(3));
+}
+
+#[test]
+#[should_panic(expected = "Fee managers can only update the stakes and fees")]
+fn test_update_config_by_fee_manager_with_the_reward_split() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let fee_manager = get_account_for_protocol(ACCOUNT_2);
+
+    Environment::with_account(get_owner_account()).create();
+    contract.grant_role(Role::FeeManager, fee_manager.clone());
+
+    Environment::with_account(fee_manager).create();
+    contract.update_config(Config {
+        miner_reward_basis_points: 5_000,
+        ..Config::default()
+    });
+}
+
+#[test]
+#[should_panic(expected = "Fee managers can only update the stakes and fees")]
+fn test_update_config_by_fee_manager_beyond_the_amounts() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let fee_manager = get_account_for_protocol(ACCOUNT_2);
+
+    Environment::with_account(get_owner_account()).create();
+    contract.grant_role(Role::FeeManager, fee_manager.clone());
+
+    Environment::with_account(fee_manager).create();
+    contract.update_config(Config {
+        max_ranking_size: 5,
+        ..raised_miner_stake()
+    });
+}
+
+#[test]
+#[should_panic(expected = "Config value out of bounds")]
+fn test_update_config_out_of_bounds() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_owner_account()).create();
+    contract.update_config(Config {
+        protocol_registration_fee: NearToken::from_near(1_000_000),
+        ..Config::default()
+    });
+}
+
+#[test]
+#[should_panic(expected = "Miner deposit is less than the minimum stake")]
+fn test_register_miner_below_updated_minimum() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_owner_account()).create();
+    contract.update_config(raised_miner_stake());
+
+    Environment::with_account(get_default_miner_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_MINER)
+        .create();
+    contract.register_miner(vec![Module::TextPrompting]);
+}
+
+#[test]
+fn test_registered_miner_keeps_registration_when_minimum_increases() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(default_culture(), vec![Module::TextPrompting], None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+
+    Environment::with_account(get_owner_account()).create();
+    contract.update_config(raised_miner_stake());
+
+    // @dev The stake is below the new minimum, the miner is still registered and can commit
+    Environment::with_account(miner.clone()).create();
+    assert!(contract.is_miner_registered(miner));
+
+    let result = contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+    assert_eq!(result, CommitMinerResult::Success);
+
+    // @dev New miners need the new minimum
+    let new_miner = get_account_for_miner("miner2.near");
+    Environment::with_account(new_miner.clone())
+        .with_attached_deposit(NearToken::from_near(2))
+        .create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    assert!(contract.is_miner_registered(new_miner));
+}
+
+#[test]
+#[should_panic(expected = "Config value out of bounds")]
+fn test_init_with_max_ranking_size_out_of_bounds() {
+    Contract::new(
+        get_owner_account(),
+        Config {
+            max_ranking_size: 0,
+            ..Config::default()
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Config value out of bounds")]
+fn test_init_with_unbonding_period_out_of_bounds() {
+    Contract::new(
+        get_owner_account(),
+        Config {
+            unbonding_period: 0,
+            ..Config::default()
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Config value out of bounds")]
+fn test_init_with_slash_above_the_stake() {
+    Contract::new(
+        get_owner_account(),
+        Config {
+            slash_basis_points: 10_001,
+            ..Config::default()
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Deposit is less than the required to request a governance decision")]
+fn test_request_governance_decision_below_the_configured_request_fee() {
+    let mut contract = Contract::new(
+        get_owner_account(),
+        Config {
+            min_request_fee: NearToken::from_near(10),
+            ..Config::default()
+        },
+    );
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(default_culture(), vec![Module::TextPrompting], None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+}
+
+#[test]
+#[should_panic(expected = "Config value out of bounds")]
+fn test_init_with_request_fee_out_of_bounds() {
+    Contract::new(
+        get_owner_account(),
+        Config {
+            min_request_fee: NearToken::from_yoctonear(0),
+            ..Config::default()
+        },
+    );
+}
+
+#[test]
+fn test_rewards_with_the_configured_miner_share() {
+    let mut contract = Contract::new(
+        get_owner_account(),
+        Config {
+            miner_reward_basis_points: 5_000,
+            ..Config::default()
+        },
+    );
+    setup_request_with_revealed_miners(&mut contract);
+
+    commit_by_validators(&mut contract, &[(VALIDATOR_1, DEFAULT_VALIDATOR_ANSWER), (VALIDATOR_2, VALIDATOR_2_ANSWER)]);
+    reveal_by_validators(&mut contract, &[VALIDATOR_1, VALIDATOR_2]);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.get_top_voters(DEFAULT_REQUEST_ID.to_string());
+
+    // @dev Half of the 5 NEAR pool goes to the 10 top miners, the other half to the 2 validators
+    assert_eq!(contract.get_rewards(get_default_miner_account()), NearToken::from_millinear(250));
+    assert_eq!(contract.get_rewards(get_account_for_validator(VALIDATOR_1)), NearToken::from_millinear(1250));
+    assert_eq!(contract.get_rewards(get_account_for_validator(VALIDATOR_2)), NearToken::from_millinear(1250));
+}
+
+#[test]
+#[should_panic(expected = "Config value out of bounds")]
+fn test_init_with_miner_share_above_the_pool() {
+    Contract::new(
+        get_owner_account(),
+        Config {
+            miner_reward_basis_points: 10_001,
+            ..Config::default()
+        },
+    );
+}
@@ -0,0 +1,108 @@
+use near_sdk::{test_utils::get_logs, PromiseError};
+
+use common::constants::{CONTRACT_ACCOUNT_ID, DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER, REVEAL_TOPTEN_TIME, VALIDATOR_1};
+use common::environment::Environment;
+use common::utils::{commit_by_validators, get_default_protocol_account, get_owner_account, reveal_by_validators, setup_request_with_revealed_miners};
+
+use earthmind_rs::{Config, Contract, DecisionDelivery, DeliveryStatus, MAX_DELIVERY_ATTEMPTS};
+
+pub mod common;
+
+fn setup_finalized_request(contract: &mut Contract) {
+    setup_request_with_revealed_miners(contract);
+
+    commit_by_validators(contract, &[(VALIDATOR_1, DEFAULT_VALIDATOR_ANSWER)]);
+    reveal_by_validators(contract, &[VALIDATOR_1]);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+}
+
+// @dev The callback can only be called by the contract itself
+fn resolve_delivery(contract: &mut Contract, call_result: Result<(), PromiseError>) -> DeliveryStatus {
+    Environment::with_account(CONTRACT_ACCOUNT_ID.parse().unwrap())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    contract.on_decision_delivered(DEFAULT_REQUEST_ID.to_string(), call_result)
+}
+
+#[test]
+fn test_finalize_request_sends_the_decision() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    assert_eq!(contract.get_decision_delivery(DEFAULT_REQUEST_ID.to_string()), None);
+
+    setup_finalized_request(&mut contract);
+
+    assert_eq!(
+        contract.get_decision_delivery(DEFAULT_REQUEST_ID.to_string()),
+        Some(DecisionDelivery {
+            status: DeliveryStatus::Pending,
+            attempts: 1,
+        })
+    );
+}
+
+#[test]
+fn test_decision_delivered() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_finalized_request(&mut contract);
+
+    let status = resolve_delivery(&mut contract, Ok(()));
+
+    assert_eq!(status, DeliveryStatus::Delivered);
+    assert_eq!(
+        contract.get_decision_delivery(DEFAULT_REQUEST_ID.to_string()),
+        Some(DecisionDelivery {
+            status: DeliveryStatus::Delivered,
+            attempts: 1,
+        })
+    );
+    assert_eq!(get_logs(), vec![format!("Decision delivered: {}", DEFAULT_REQUEST_ID)]);
+}
+
+#[test]
+fn test_decision_delivery_is_retried_when_client_fails() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_finalized_request(&mut contract);
+
+    let status = resolve_delivery(&mut contract, Err(PromiseError::Failed));
+
+    assert_eq!(status, DeliveryStatus::Pending);
+    assert_eq!(
+        contract.get_decision_delivery(DEFAULT_REQUEST_ID.to_string()),
+        Some(DecisionDelivery {
+            status: DeliveryStatus::Pending,
+            attempts: 2,
+        })
+    );
+    assert_eq!(get_logs(), vec![format!("Decision delivery failed: {}", DEFAULT_REQUEST_ID)]);
+
+    let status = resolve_delivery(&mut contract, Ok(()));
+
+    assert_eq!(status, DeliveryStatus::Delivered);
+}
+
+#[test]
+fn test_decision_delivery_fails_after_max_attempts() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_finalized_request(&mut contract);
+
+    for _ in 1..MAX_DELIVERY_ATTEMPTS {
+        assert_eq!(resolve_delivery(&mut contract, Err(PromiseError::Failed)), DeliveryStatus::Pending);
+    }
+
+    let status = resolve_delivery(&mut contract, Err(PromiseError::Failed));
+
+    assert_eq!(status, DeliveryStatus::Failed);
+    assert_eq!(
+        contract.get_decision_delivery(DEFAULT_REQUEST_ID.to_string()),
+        Some(DecisionDelivery {
+            status: DeliveryStatus::Failed,
+            attempts: MAX_DELIVERY_ATTEMPTS,
+        })
+    );
+}
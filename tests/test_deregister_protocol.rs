@@ -0,0 +1,169 @@
+use near_sdk::NearToken;
+use serde_json::json;
+
+use common::constants::{ACCOUNT_1, COMMIT_VALIDATOR_TIME, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{assert_logs, default_culture, get_default_protocol_account, get_owner_account, get_request_id, get_transfers, register_protocol};
+
+use earthmind_rs::{Config, Contract, DeregisterProtocolResult, FinalizeRequestResult, Module, RegisterProtocolResult};
+
+pub mod common;
+
+const SECOND_MESSAGE_TO_REQUEST: &str = "Should we remove this old NFT from our protocol?";
+
+#[test]
+fn test_deregister_protocol() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.deregister_protocol();
+
+    assert_eq!(result, DeregisterProtocolResult::Success);
+    assert!(!contract.is_protocol_registered(get_default_protocol_account()));
+    assert!(contract.get_protocols(None, None).is_empty());
+    assert_eq!(get_transfers(), vec![(get_default_protocol_account(), NearToken::from_millinear(2500))]);
+
+    assert_logs(vec![Log::Event {
+        event_name: "protocol_deregistered".to_string(),
+        data: vec![("account", json![ACCOUNT_1]), ("refund", json![NearToken::from_millinear(2500)])],
+    }]);
+}
+
+#[test]
+fn test_deregister_protocol_refunds_the_configured_share() {
+    let mut contract = Contract::new(
+        get_owner_account(),
+        Config {
+            deregistration_refund_basis_points: 10_000,
+            ..Config::default()
+        },
+    );
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    contract.deregister_protocol();
+
+    assert_eq!(get_transfers(), vec![(get_default_protocol_account(), DEFAULT_DEPOSIT_PROTOCOL)]);
+}
+
+#[test]
+fn test_deregister_protocol_without_refund() {
+    let mut contract = Contract::new(
+        get_owner_account(),
+        Config {
+            deregistration_refund_basis_points: 0,
+            ..Config::default()
+        },
+    );
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.deregister_protocol();
+
+    assert_eq!(result, DeregisterProtocolResult::Success);
+    assert!(get_transfers().is_empty());
+}
+
+#[test]
+fn test_deregister_protocol_when_not_registered() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.deregister_protocol();
+
+    assert_eq!(result, DeregisterProtocolResult::NotRegistered);
+    assert_logs(vec![Log::Message(format!("Protocol not registered: {}", ACCOUNT_1))]);
+}
+
+#[test]
+fn test_deregister_protocol_with_unfinished_requests() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    let result = contract.deregister_protocol();
+
+    assert_eq!(result, DeregisterProtocolResult::UnfinishedRequests);
+    assert_logs(vec![Log::Message(format!("Protocol has unfinished requests: {}", ACCOUNT_1))]);
+    assert!(contract.is_protocol_registered(get_default_protocol_account()));
+    assert!(get_transfers().is_empty());
+
+    assert_eq!(contract.finalize_request(DEFAULT_REQUEST_ID.to_string()), FinalizeRequestResult::Success);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    let result = contract.deregister_protocol();
+
+    assert_eq!(result, DeregisterProtocolResult::Success);
+    assert_eq!(
+        contract.get_requests_by_protocol(get_default_protocol_account(), None, None),
+        vec![DEFAULT_REQUEST_ID.to_string()]
+    );
+    assert!(contract.get_request_summary(DEFAULT_REQUEST_ID.to_string()).is_some());
+}
+
+#[test]
+fn test_deregister_protocol_after_finalizing_one_of_its_requests() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(COMMIT_VALIDATOR_TIME)
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.request_governance_decision(SECOND_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    assert_eq!(contract.finalize_request(DEFAULT_REQUEST_ID.to_string()), FinalizeRequestResult::Success);
+    assert_eq!(
+        contract.finalize_request(DEFAULT_REQUEST_ID.to_string()),
+        FinalizeRequestResult::AlreadyFinalized
+    );
+
+    let result = contract.deregister_protocol();
+
+    assert_eq!(result, DeregisterProtocolResult::UnfinishedRequests);
+    assert!(contract.is_protocol_registered(get_default_protocol_account()));
+    assert!(contract
+        .get_request_summary(get_request_id(&get_default_protocol_account(), SECOND_MESSAGE_TO_REQUEST, 1))
+        .is_some());
+}
+
+#[test]
+fn test_register_protocol_again_after_deregistering() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    contract.deregister_protocol();
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    let result = contract.register_protocol(default_culture(), vec![Module::TextPrompting], None);
+
+    assert_eq!(result, RegisterProtocolResult::Success);
+    assert_eq!(contract.get_protocol_version(get_default_protocol_account()), Some(1));
+}
+
+#[test]
+#[should_panic(expected = "Config value out of bounds")]
+fn test_deregistration_refund_above_the_registration_fee() {
+    Contract::new(
+        get_owner_account(),
+        Config {
+            deregistration_refund_basis_points: 10_001,
+            ..Config::default()
+        },
+    );
+}
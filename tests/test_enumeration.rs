@@ -0,0 +1,232 @@
+use common::constants::{
+    ACCOUNT_2, COMMIT_VALIDATOR_TIME, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MESSAGE_TO_REQUEST,
+    DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME, UNBONDING_TIME, VALIDATOR_1, VALIDATOR_2, VALIDATOR_3,
+};
+use common::environment::Environment;
+use common::utils::{
+    get_account_for_miner, get_account_for_protocol, get_account_for_validator, get_default_miner_account, get_default_protocol_account, get_owner_account,
+    get_request_id, group_registered_miners, register_protocol, setup_request_with_revealed_miners,
+};
+
+use earthmind_rs::{Config, Contract, Module, Participant};
+
+pub mod common;
+
+const SECOND_MESSAGE_TO_REQUEST: &str = "Should we remove this old NFT from our protocol?";
+
+#[test]
+fn test_get_miners_in_registration_order_and_paginated() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    for miner in ["miner3.near", "miner1.near", "miner2.near"] {
+        Environment::with_account(get_account_for_miner(miner))
+            .with_attached_deposit(DEFAULT_DEPOSIT_MINER)
+            .create();
+        contract.register_miner(vec![Module::TextPrompting]);
+    }
+
+    assert_eq!(
+        contract.get_miners(None, None),
+        vec![
+            get_account_for_miner("miner3.near"),
+            get_account_for_miner("miner1.near"),
+            get_account_for_miner("miner2.near")
+        ]
+    );
+    assert_eq!(contract.get_miners(Some(1), Some(1)), vec![get_account_for_miner("miner1.near")]);
+    assert!(contract.get_miners(Some(3), None).is_empty());
+}
+
+#[test]
+fn test_get_miners_after_a_miner_leaves() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    for miner in ["miner1.near", "miner2.near", "miner3.near"] {
+        Environment::with_account(get_account_for_miner(miner))
+            .with_attached_deposit(DEFAULT_DEPOSIT_MINER)
+            .create();
+        contract.register_miner(vec![Module::TextPrompting]);
+    }
+
+    Environment::with_account(get_default_miner_account()).create();
+    contract.request_unstake(Participant::Miner);
+    Environment::with_account(get_default_miner_account())
+        .with_block_timestamp(UNBONDING_TIME)
+        .create();
+    contract.withdraw_stake(Participant::Miner);
+
+    // @dev The miners that stay keep their order
+    let expected = vec![get_account_for_miner("miner2.near"), get_account_for_miner("miner3.near")];
+    assert_eq!(contract.get_miners(None, None), expected);
+    assert_eq!(contract.get_miners_by_module(Module::TextPrompting, None, None), expected);
+}
+
+#[test]
+fn test_get_miners_after_withdraw_stake() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    contract.request_unstake(Participant::Miner);
+
+    assert_eq!(contract.get_miners(None, None), vec![miner.clone()]);
+
+    Environment::with_account(miner).with_block_timestamp(UNBONDING_TIME).create();
+    contract.withdraw_stake(Participant::Miner);
+
+    assert!(contract.get_miners(None, None).is_empty());
+}
+
+#[test]
+fn test_get_validators() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    for validator in [VALIDATOR_2, VALIDATOR_3, VALIDATOR_1] {
+        Environment::with_account(get_account_for_validator(validator))
+            .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+            .create();
+        contract.register_validator();
+    }
+
+    assert_eq!(
+        contract.get_validators(Some(0), Some(2)),
+        vec![get_account_for_validator(VALIDATOR_2), get_account_for_validator(VALIDATOR_3)]
+    );
+}
+
+#[test]
+fn test_get_validators_after_a_validator_leaves_and_registers_again() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    for validator in [VALIDATOR_1, VALIDATOR_2, VALIDATOR_3] {
+        Environment::with_account(get_account_for_validator(validator))
+            .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+            .create();
+        contract.register_validator();
+    }
+
+    Environment::with_account(get_account_for_validator(VALIDATOR_2)).create();
+    contract.request_unstake(Participant::Validator);
+    Environment::with_account(get_account_for_validator(VALIDATOR_2))
+        .with_block_timestamp(UNBONDING_TIME)
+        .create();
+    contract.withdraw_stake(Participant::Validator);
+
+    assert_eq!(
+        contract.get_validators(None, None),
+        vec![get_account_for_validator(VALIDATOR_1), get_account_for_validator(VALIDATOR_3)]
+    );
+
+    // @dev An account that registers again is listed after the others
+    Environment::with_account(get_account_for_validator(VALIDATOR_2))
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+
+    assert_eq!(
+        contract.get_validators(None, None),
+        vec![
+            get_account_for_validator(VALIDATOR_1),
+            get_account_for_validator(VALIDATOR_3),
+            get_account_for_validator(VALIDATOR_2)
+        ]
+    );
+    assert_eq!(contract.get_validators(Some(2), None), vec![get_account_for_validator(VALIDATOR_2)]);
+}
+
+#[test]
+fn test_get_protocols() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    register_protocol(
+        &mut contract,
+        &get_account_for_protocol(ACCOUNT_2),
+        vec![Module::TextPrompting, Module::ObjectRecognition],
+    );
+    register_protocol(
+        &mut contract,
+        &get_account_for_protocol("account1.near"),
+        vec![Module::TextPrompting, Module::ObjectRecognition],
+    );
+
+    assert_eq!(
+        contract.get_protocols(None, None),
+        vec![get_account_for_protocol(ACCOUNT_2), get_default_protocol_account()]
+    );
+}
+
+#[test]
+fn test_get_requests_by_protocol() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let protocol = get_default_protocol_account();
+    register_protocol(
+        &mut contract,
+        &get_account_for_protocol("account1.near"),
+        vec![Module::TextPrompting, Module::ObjectRecognition],
+    );
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+    contract.request_governance_decision(SECOND_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    assert_eq!(
+        contract.get_requests_by_protocol(protocol.clone(), None, None),
+        vec![DEFAULT_REQUEST_ID.to_string(), get_request_id(&protocol, SECOND_MESSAGE_TO_REQUEST, 1)]
+    );
+    assert_eq!(
+        contract.get_requests_by_protocol(protocol, Some(1), Some(10)),
+        vec![get_request_id(&get_default_protocol_account(), SECOND_MESSAGE_TO_REQUEST, 1)]
+    );
+    assert!(contract.get_requests_by_protocol(get_account_for_protocol(ACCOUNT_2), None, None).is_empty());
+}
+
+#[test]
+fn test_get_active_requests() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    register_protocol(
+        &mut contract,
+        &get_account_for_protocol("account1.near"),
+        vec![Module::TextPrompting, Module::ObjectRecognition],
+    );
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    // @dev The second request starts later, so it is still active when the first one ends
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(COMMIT_VALIDATOR_TIME)
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.request_governance_decision(SECOND_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    let second_request_id = get_request_id(&get_default_protocol_account(), SECOND_MESSAGE_TO_REQUEST, 1);
+
+    assert_eq!(
+        contract.get_active_requests(None, None),
+        vec![DEFAULT_REQUEST_ID.to_string(), second_request_id.clone()]
+    );
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    assert_eq!(contract.get_active_requests(None, None), vec![second_request_id.clone()]);
+
+    // @dev Finalized requests leave the index, the page starts with the requests that are still listed
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(contract.get_active_requests(None, Some(1)), vec![second_request_id]);
+}
+
+#[test]
+fn test_get_revealed_miners() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_revealed_miners(&mut contract);
+
+    let revealed = contract.get_revealed_miners(DEFAULT_REQUEST_ID.to_string(), None, None).unwrap();
+    assert_eq!(revealed, group_registered_miners());
+
+    let revealed = contract.get_revealed_miners(DEFAULT_REQUEST_ID.to_string(), Some(8), Some(5)).unwrap();
+    assert_eq!(revealed, group_registered_miners()[8..].to_vec());
+
+    assert!(contract.get_revealed_miners("unknown".to_string(), None, None).is_none());
+}
@@ -1,15 +1,20 @@
+use near_sdk::NearToken;
+
 use earthmind_rs::{
-    CommitMinerLog, CommitValidatorLog, EventLog, EventLogVariant, RegisterMinerLog, RegisterProtocolLog, RegisterRequestLog, RegisterValidatorLog,
-    RevealMinerLog, RevealValidatorLog, ToptenMinersLog,
+    AddStakeLog, Answer, ClaimRewardsLog, CommitMinerLog, CommitValidatorLog, CommitteeConfig, Config, ConfigUpdatedLog, CultureProfile, DecreaseStakeLog,
+    EventLog, EventLogVariant, MinerModulesUpdatedLog, Module, ModuleAddedLog, ModuleRemovedLog, OwnershipTransferredLog, Participant, PauseLog,
+    ProtocolDeregisteredLog, ProtocolUpdatedLog, RegisterMinerLog, RegisterProtocolLog, RegisterRequestLog, RegisterValidatorLog, RequestFinalizedLog,
+    RequestUnstakeLog, RevealMinerLog, RevealValidatorLog, RewardDistributedLog, Role, RoleGrantedLog, RoleRevokedLog, SlashLog, TopMinersLog, UnpauseLog,
+    WithdrawStakeLog,
 };
 
 #[test]
 fn test_format_register_protocol() {
     let expected =
-        r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_protocol","data":[{"account":"miner1.near"},{"account":"validator1.near"}]}"#;
+        r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"register_protocol","data":[{"account":"miner1.near"},{"account":"validator1.near"}]}"#;
     let log = EventLog {
         standard: "emip001".to_string(),
-        version: "1.0.0".to_string(),
+        version: "2.0.0".to_string(),
         event: EventLogVariant::RegisterProtocol(vec![
             RegisterProtocolLog {
                 account: "miner1.near".parse().unwrap(),
@@ -24,28 +29,44 @@ fn test_format_register_protocol() {
 
 #[test]
 fn test_format_register_miner() {
-    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_miner","data":[{"miner":"miner1.near"},{"miner":"miner2.near"}]}"#;
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"register_miner","data":[{"miner":"miner1.near","modules":["TextPrompting"]},{"miner":"miner2.near","modules":["TextPrompting","ObjectRecognition"]}]}"#;
     let log = EventLog {
         standard: "emip001".to_string(),
-        version: "1.0.0".to_string(),
+        version: "2.0.0".to_string(),
         event: EventLogVariant::RegisterMiner(vec![
             RegisterMinerLog {
                 miner: "miner1.near".parse().unwrap(),
+                modules: vec![Module::TextPrompting],
             },
             RegisterMinerLog {
                 miner: "miner2.near".parse().unwrap(),
+                modules: vec![Module::TextPrompting, Module::ObjectRecognition],
             },
         ]),
     };
     assert_eq!(expected, log.to_string());
 }
 
+#[test]
+fn test_format_miner_modules_updated() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"miner_modules_updated","data":[{"miner":"miner1.near","modules":["ObjectRecognition"]}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::MinerModulesUpdated(vec![MinerModulesUpdatedLog {
+            miner: "miner1.near".parse().unwrap(),
+            modules: vec![Module::ObjectRecognition],
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
 #[test]
 fn test_format_register_validator() {
-    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_validator","data":[{"validator":"validator1.near"},{"validator":"validator2.near"}]}"#;
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"register_validator","data":[{"validator":"validator1.near"},{"validator":"validator2.near"}]}"#;
     let log = EventLog {
         standard: "emip001".to_string(),
-        version: "1.0.0".to_string(),
+        version: "2.0.0".to_string(),
         event: EventLogVariant::RegisterValidator(vec![
             RegisterValidatorLog {
                 validator: "validator1.near".parse().unwrap(),
@@ -60,28 +81,97 @@ fn test_format_register_validator() {
 
 #[test]
 fn test_format_register_request() {
-    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_request","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726"},{"request_id":"38d15af71379737839e4738066fd4091428081d6a57498b2852337a195bc9f5f"}]}"#;
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"register_request","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","culture":{"document_hash":"d5c53010c394ebd738ac31123aa35bd43ccbaf783b6a27c6df7aa3e0bddd003a","tags":["governance","defi"]},"protocol_version":2},{"request_id":"38d15af71379737839e4738066fd4091428081d6a57498b2852337a195bc9f5f","culture":null,"protocol_version":0}]}"#;
     let log = EventLog {
         standard: "emip001".to_string(),
-        version: "1.0.0".to_string(),
+        version: "2.0.0".to_string(),
         event: EventLogVariant::RegisterRequest(vec![
             RegisterRequestLog {
                 request_id: "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726".to_string(),
+                culture: Some(CultureProfile {
+                    document_hash: "d5c53010c394ebd738ac31123aa35bd43ccbaf783b6a27c6df7aa3e0bddd003a".to_string(),
+                    tags: vec!["governance".to_string(), "defi".to_string()],
+                }),
+                protocol_version: 2,
             },
             RegisterRequestLog {
                 request_id: "38d15af71379737839e4738066fd4091428081d6a57498b2852337a195bc9f5f".to_string(),
+                culture: None,
+                protocol_version: 0,
             },
         ]),
     };
     assert_eq!(expected, log.to_string());
 }
 
+#[test]
+fn test_format_protocol_updated() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"protocol_updated","data":[{"account":"account1.near","version":2,"culture":{"document_hash":"d5c53010c394ebd738ac31123aa35bd43ccbaf783b6a27c6df7aa3e0bddd003a","tags":["governance"]}}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::ProtocolUpdated(vec![ProtocolUpdatedLog {
+            account: "account1.near".parse().unwrap(),
+            version: 2,
+            culture: CultureProfile {
+                document_hash: "d5c53010c394ebd738ac31123aa35bd43ccbaf783b6a27c6df7aa3e0bddd003a".to_string(),
+                tags: vec!["governance".to_string()],
+            },
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_module_added() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"module_added","data":[{"account":"account1.near","version":2,"module":"ObjectRecognition"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::ModuleAdded(vec![ModuleAddedLog {
+            account: "account1.near".parse().unwrap(),
+            version: 2,
+            module: Module::ObjectRecognition,
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_module_removed() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"module_removed","data":[{"account":"account1.near","version":3,"module":"TextPrompting"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::ModuleRemoved(vec![ModuleRemovedLog {
+            account: "account1.near".parse().unwrap(),
+            version: 3,
+            module: Module::TextPrompting,
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_protocol_deregistered() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"protocol_deregistered","data":[{"account":"account1.near","refund":"2500000000000000000000000"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::ProtocolDeregistered(vec![ProtocolDeregisteredLog {
+            account: "account1.near".parse().unwrap(),
+            refund: NearToken::from_millinear(2500),
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
 #[test]
 fn test_format_commit_miner() {
-    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"commit_miner","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","answer":"3910deb8f11de66388bddcc1eb1bf1e33319b71a18df2c1019e6d72c6d00f464"}]}"#;
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"commit_miner","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","answer":"3910deb8f11de66388bddcc1eb1bf1e33319b71a18df2c1019e6d72c6d00f464"}]}"#;
     let log = EventLog {
         standard: "emip001".to_string(),
-        version: "1.0.0".to_string(),
+        version: "2.0.0".to_string(),
         event: EventLogVariant::CommitMiner(vec![CommitMinerLog {
             request_id: "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726".to_string(),
             answer: "3910deb8f11de66388bddcc1eb1bf1e33319b71a18df2c1019e6d72c6d00f464".to_string(),
@@ -93,10 +183,10 @@ fn test_format_commit_miner() {
 
 #[test]
 fn test_format_commit_validator() {
-    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"commit_validator","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","answer":"3910deb8f11de66388bddcc1eb1bf1e33319b71a18df2c1019e6d72c6d00f464"}]}"#;
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"commit_validator","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","answer":"3910deb8f11de66388bddcc1eb1bf1e33319b71a18df2c1019e6d72c6d00f464"}]}"#;
     let log = EventLog {
         standard: "emip001".to_string(),
-        version: "1.0.0".to_string(),
+        version: "2.0.0".to_string(),
         event: EventLogVariant::CommitValidator(vec![CommitValidatorLog {
             request_id: "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726".to_string(),
             answer: "3910deb8f11de66388bddcc1eb1bf1e33319b71a18df2c1019e6d72c6d00f464".to_string(),
@@ -108,13 +198,13 @@ fn test_format_commit_validator() {
 
 #[test]
 fn test_format_reveal_miner() {
-    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"reveal_miner","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","answer":true,"message":"It's a cool NFT"}]}"#;
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"reveal_miner","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","answer":{"YesNo":true},"message":"It's a cool NFT"}]}"#;
     let log = EventLog {
         standard: "emip001".to_string(),
-        version: "1.0.0".to_string(),
+        version: "2.0.0".to_string(),
         event: EventLogVariant::RevealMiner(vec![RevealMinerLog {
             request_id: "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726".to_string(),
-            answer: true,
+            answer: Answer::YesNo(true),
             message: "It's a cool NFT".to_string(),
         }]),
     };
@@ -124,10 +214,10 @@ fn test_format_reveal_miner() {
 
 #[test]
 fn test_format_reveal_validator() {
-    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"reveal_validator","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","answer":["miner1.near","miner2.near","miner3.near","miner4.near","miner5.near","miner6.near","miner7.near","miner8.near","miner9.near","miner10.near"],"message":"It's a cool NFT"}]}"#;
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"reveal_validator","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","answer":["miner1.near","miner2.near","miner3.near","miner4.near","miner5.near","miner6.near","miner7.near","miner8.near","miner9.near","miner10.near"],"message":"It's a cool NFT"}]}"#;
     let log = EventLog {
         standard: "emip001".to_string(),
-        version: "1.0.0".to_string(),
+        version: "2.0.0".to_string(),
         event: EventLogVariant::RevealValidator(vec![RevealValidatorLog {
             request_id: "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726".to_string(),
             answer: vec![
@@ -150,14 +240,15 @@ fn test_format_reveal_validator() {
 }
 
 #[test]
-fn test_format_topten_miners() {
-    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"topten_miners","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","topten":[["miner1.near",3],["miner2.near",3],["miner3.near",3],["miner4.near",3],["miner5.near",3],["miner6.near",3],["miner7.near",3],["miner8.near",3],["miner9.near",3],["miner10.near",3]]}]}"#;
+fn test_format_top_miners() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"top_miners","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","ranking_size":10,"top_miners":[["miner1.near",3],["miner2.near",3],["miner3.near",3],["miner4.near",3],["miner5.near",3],["miner6.near",3],["miner7.near",3],["miner8.near",3],["miner9.near",3],["miner10.near",3]]}]}"#;
     let log = EventLog {
         standard: "emip001".to_string(),
-        version: "1.0.0".to_string(),
-        event: EventLogVariant::ToptenMiners(vec![ToptenMinersLog {
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::TopMiners(vec![TopMinersLog {
             request_id: "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726".to_string(),
-            topten: vec![
+            ranking_size: 10,
+            top_miners: vec![
                 ("miner1.near".parse().unwrap(), 3),
                 ("miner2.near".parse().unwrap(), 3),
                 ("miner3.near".parse().unwrap(), 3),
@@ -173,3 +264,228 @@ fn test_format_topten_miners() {
     };
     assert_eq!(expected, log.to_string());
 }
+
+#[test]
+fn test_format_request_unstake() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"request_unstake","data":[{"account":"miner1.near","participant":"Miner","amount":"1000000000000000000000000","available_at":172800100000000000}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::RequestUnstake(vec![RequestUnstakeLog {
+            account: "miner1.near".parse().unwrap(),
+            participant: Participant::Miner,
+            amount: NearToken::from_near(1),
+            available_at: 172800100000000000,
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_withdraw_stake() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"withdraw_stake","data":[{"account":"validator1.near","participant":"Validator","amount":"10000000000000000000000000"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::WithdrawStake(vec![WithdrawStakeLog {
+            account: "validator1.near".parse().unwrap(),
+            participant: Participant::Validator,
+            amount: NearToken::from_near(10),
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_add_stake() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"add_stake","data":[{"account":"miner1.near","participant":"Miner","amount":"2000000000000000000000000","stake":"3000000000000000000000000"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::AddStake(vec![AddStakeLog {
+            account: "miner1.near".parse().unwrap(),
+            participant: Participant::Miner,
+            amount: NearToken::from_near(2),
+            stake: NearToken::from_near(3),
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_decrease_stake() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"decrease_stake","data":[{"account":"validator1.near","participant":"Validator","amount":"5000000000000000000000000","stake":"10000000000000000000000000","available_at":172800100000000}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::DecreaseStake(vec![DecreaseStakeLog {
+            account: "validator1.near".parse().unwrap(),
+            participant: Participant::Validator,
+            amount: NearToken::from_near(5),
+            stake: NearToken::from_near(10),
+            available_at: 172800100000000,
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_slash() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"slash","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","account":"miner1.near","participant":"Miner","amount":"100000000000000000000000"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::Slash(vec![SlashLog {
+            request_id: "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726".to_string(),
+            account: "miner1.near".parse().unwrap(),
+            participant: Participant::Miner,
+            amount: NearToken::from_millinear(100),
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_reward_distributed() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"reward_distributed","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","account":"miner1.near","amount":"350000000000000000000000"},{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","account":"validator1.near","amount":"1500000000000000000000000"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::RewardDistributed(vec![
+            RewardDistributedLog {
+                request_id: "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726".to_string(),
+                account: "miner1.near".parse().unwrap(),
+                amount: NearToken::from_millinear(350),
+            },
+            RewardDistributedLog {
+                request_id: "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726".to_string(),
+                account: "validator1.near".parse().unwrap(),
+                amount: NearToken::from_millinear(1500),
+            },
+        ]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_claim_rewards() {
+    let expected =
+        r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"claim_rewards","data":[{"account":"miner1.near","amount":"350000000000000000000000"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::ClaimRewards(vec![ClaimRewardsLog {
+            account: "miner1.near".parse().unwrap(),
+            amount: NearToken::from_millinear(350),
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_request_finalized() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"request_finalized","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","decision":{"Choice":2},"answers":10,"support":6,"revealed_miners":12,"revealed_validators":3}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::RequestFinalized(vec![RequestFinalizedLog {
+            request_id: "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726".to_string(),
+            decision: Some(Answer::Choice(2)),
+            answers: 10,
+            support: 6,
+            revealed_miners: 12,
+            revealed_validators: 3,
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_ownership_transferred() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"ownership_transferred","data":[{"previous_owner":"owner.near","new_owner":"account1.near"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::OwnershipTransferred(vec![OwnershipTransferredLog {
+            previous_owner: "owner.near".parse().unwrap(),
+            new_owner: "account1.near".parse().unwrap(),
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_role_granted() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"role_granted","data":[{"role":"FeeManager","account":"account1.near","sender":"owner.near"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::RoleGranted(vec![RoleGrantedLog {
+            role: Role::FeeManager,
+            account: "account1.near".parse().unwrap(),
+            sender: "owner.near".parse().unwrap(),
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_role_revoked() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"role_revoked","data":[{"role":"Admin","account":"account1.near","sender":"owner.near"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::RoleRevoked(vec![RoleRevokedLog {
+            role: Role::Admin,
+            account: "account1.near".parse().unwrap(),
+            sender: "owner.near".parse().unwrap(),
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_pause_and_unpause() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"pause","data":[{"account":"owner.near"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::Pause(vec![PauseLog {
+            account: "owner.near".parse().unwrap(),
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"unpause","data":[{"account":"owner.near"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::Unpause(vec![UnpauseLog {
+            account: "owner.near".parse().unwrap(),
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_config_updated() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"config_updated","data":[{"sender":"owner.near","previous":{"min_miner_stake":"1000000000000000000000000","min_validator_stake":"10000000000000000000000000","protocol_registration_fee":"5000000000000000000000000","accept_legacy_commitments":true,"max_ranking_size":10,"vote_weighting":"Equal","committee":null,"deregistration_refund_basis_points":5000,"unbonding_period":172800000000000,"slash_basis_points":1000,"min_request_fee":"1000000000000000000000000","miner_reward_basis_points":7000},"config":{"min_miner_stake":"2000000000000000000000000","min_validator_stake":"10000000000000000000000000","protocol_registration_fee":"5000000000000000000000000","accept_legacy_commitments":true,"max_ranking_size":10,"vote_weighting":"Equal","committee":{"miners":10,"validators":5,"stake_weighted":true},"deregistration_refund_basis_points":5000,"unbonding_period":172800000000000,"slash_basis_points":1000,"min_request_fee":"1000000000000000000000000","miner_reward_basis_points":7000}}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::ConfigUpdated(vec![ConfigUpdatedLog {
+            sender: "owner.near".parse().unwrap(),
+            previous: Config::default(),
+            config: Config {
+                min_miner_stake: NearToken::from_near(2),
+                committee: Some(CommitteeConfig {
+                    miners: 10,
+                    validators: 5,
+                    stake_weighted: true,
+                }),
+                ..Config::default()
+            },
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
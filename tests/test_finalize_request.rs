@@ -0,0 +1,258 @@
+use near_sdk::test_utils::get_logs;
+
+use common::constants::{
+    DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER, REVEAL_MINER_TIME,
+    REVEAL_TOPTEN_TIME, REVEAL_VALIDATOR_TIME, VALIDATOR_1,
+};
+use common::environment::Environment;
+use common::utils::{
+    commit_by_validators, default_culture, get_default_protocol_account, get_owner_account, group_registered_miners, reveal_by_validators,
+    setup_request_with_revealed_miners,
+};
+
+use earthmind_rs::{hash_miner_commitment, Answer, AnswerKind, Config, Contract, DecisionResult, FinalizeRequestResult, Module, RevealMinerResult};
+
+pub mod common;
+
+const MINER_MESSAGE: &str = "It's a cool NFT";
+const SALT: &str = "6c2bf8a40d1e79b3a5f0e4c8d2b71a93";
+
+// @dev The first `yes_answers` miners answer yes and the rest answer no
+fn yes_no_answers(yes_answers: usize) -> Vec<Answer> {
+    (0..10).map(|index| Answer::YesNo(index < yes_answers)).collect()
+}
+
+// @dev Each of the ten miners reveals its answer of the list, then validator1 votes for all of them
+fn setup_request_with_answers(contract: &mut Contract, answer_kind: Option<AnswerKind>, answers: &[Answer]) {
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, answer_kind, None, None, None);
+
+    let miners = group_registered_miners();
+
+    for (miner, answer) in miners.iter().zip(answers) {
+        Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+        contract.register_miner(vec![Module::TextPrompting]);
+
+        let commitment = hash_miner_commitment(DEFAULT_REQUEST_ID, miner.as_str(), answer.into(), MINER_MESSAGE, SALT);
+        contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), commitment);
+    }
+
+    for (miner, answer) in miners.iter().zip(answers) {
+        Environment::with_account(miner.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
+        let result = contract.reveal_by_miner(
+            DEFAULT_REQUEST_ID.to_string(),
+            answer.clone(),
+            MINER_MESSAGE.to_string(),
+            Some(SALT.to_string()),
+        );
+        assert_eq!(result, RevealMinerResult::Success);
+    }
+
+    commit_by_validators(contract, &[(VALIDATOR_1, DEFAULT_VALIDATOR_ANSWER)]);
+    reveal_by_validators(contract, &[VALIDATOR_1]);
+}
+
+fn finalize_default_request(contract: &mut Contract) -> FinalizeRequestResult {
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string())
+}
+
+#[test]
+fn test_finalize_request() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_revealed_miners(&mut contract);
+
+    commit_by_validators(&mut contract, &[(VALIDATOR_1, DEFAULT_VALIDATOR_ANSWER)]);
+    reveal_by_validators(&mut contract, &[VALIDATOR_1]);
+
+    let result = finalize_default_request(&mut contract);
+
+    assert_eq!(result, FinalizeRequestResult::Success);
+    assert_eq!(
+        contract.get_decision_result(DEFAULT_REQUEST_ID.to_string()),
+        Some(DecisionResult {
+            decision: Some(Answer::YesNo(true)),
+            answers: 10,
+            support: 10,
+            revealed_miners: 10,
+            committed_miners: 10,
+            revealed_validators: 1,
+            committed_validators: 1,
+        })
+    );
+
+    // @dev The top ten is computed and the rewards distributed before the request is finalized
+    let logs = get_logs();
+    assert_eq!(logs.len(), 3);
+    assert!(logs[0].contains(r#""event":"top_miners""#));
+    assert!(logs[1].contains(r#""event":"reward_distributed""#));
+    assert_eq!(
+        logs[2],
+        r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"request_finalized","data":[{"request_id":"73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b","decision":{"YesNo":true},"answers":10,"support":10,"revealed_miners":10,"revealed_validators":1}]}"#
+    );
+}
+
+#[test]
+fn test_finalize_request_when_most_miners_answer_no() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_answers(&mut contract, None, &yes_no_answers(4));
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.get_top_voters(DEFAULT_REQUEST_ID.to_string());
+
+    let result = finalize_default_request(&mut contract);
+    assert_eq!(result, FinalizeRequestResult::Success);
+
+    let decision = contract.get_decision_result(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    assert_eq!(decision.decision, Some(Answer::YesNo(false)));
+    assert_eq!((decision.answers, decision.support), (10, 6));
+
+    // @dev The top ten was already computed, so only the finalization is logged
+    assert_eq!(get_logs().len(), 1);
+}
+
+#[test]
+fn test_finalize_request_when_tied() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_answers(&mut contract, None, &yes_no_answers(5));
+
+    finalize_default_request(&mut contract);
+
+    let decision = contract.get_decision_result(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    assert_eq!(decision.decision, Some(Answer::YesNo(false)));
+    assert_eq!((decision.answers, decision.support), (10, 5));
+}
+
+#[test]
+fn test_finalize_request_with_multiple_choice() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let answers: Vec<Answer> = [2, 0, 2, 1, 2, 0, 1, 2, 0, 3].into_iter().map(Answer::Choice).collect();
+    setup_request_with_answers(&mut contract, Some(AnswerKind::MultipleChoice { options: 4 }), &answers);
+
+    finalize_default_request(&mut contract);
+
+    let decision = contract.get_decision_result(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    assert_eq!(decision.decision, Some(Answer::Choice(2)));
+    assert_eq!((decision.answers, decision.support), (10, 4));
+}
+
+#[test]
+fn test_finalize_request_with_multiple_choice_when_tied() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let answers: Vec<Answer> = [3, 1, 3, 1, 3, 1, 0, 0, 2, 2].into_iter().map(Answer::Choice).collect();
+    setup_request_with_answers(&mut contract, Some(AnswerKind::MultipleChoice { options: 4 }), &answers);
+
+    finalize_default_request(&mut contract);
+
+    // @dev A tie goes to the lowest option
+    let decision = contract.get_decision_result(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    assert_eq!(decision.decision, Some(Answer::Choice(1)));
+    assert_eq!(decision.support, 3);
+}
+
+#[test]
+fn test_finalize_request_with_score() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let answers: Vec<Answer> = [70, -20, 15, 100, 40, 55, 40, 0, 90, 35].into_iter().map(Answer::Score).collect();
+    setup_request_with_answers(&mut contract, Some(AnswerKind::Score { min: -100, max: 100 }), &answers);
+
+    finalize_default_request(&mut contract);
+
+    // @dev Sorted scores are -20, 0, 15, 35, 40, 40, 55, 70, 90, 100 so the lower median is 40
+    let decision = contract.get_decision_result(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    assert_eq!(decision.decision, Some(Answer::Score(40)));
+    assert_eq!((decision.answers, decision.support), (10, 2));
+}
+
+#[test]
+fn test_finalize_request_with_content() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let first = "a".repeat(64);
+    let second = "b".repeat(64);
+    let answers: Vec<Answer> = (0..10)
+        .map(|index| Answer::Content(if index < 3 { first.clone() } else { second.clone() }))
+        .collect();
+    setup_request_with_answers(&mut contract, Some(AnswerKind::Content), &answers);
+
+    finalize_default_request(&mut contract);
+
+    let decision = contract.get_decision_result(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    assert_eq!(decision.decision, Some(Answer::Content(second)));
+    assert_eq!(decision.support, 7);
+}
+
+#[test]
+fn test_finalize_request_without_votes() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    let result = finalize_default_request(&mut contract);
+
+    assert_eq!(result, FinalizeRequestResult::Success);
+    assert_eq!(
+        contract.get_decision_result(DEFAULT_REQUEST_ID.to_string()),
+        Some(DecisionResult {
+            decision: None,
+            answers: 0,
+            support: 0,
+            revealed_miners: 0,
+            committed_miners: 0,
+            revealed_validators: 0,
+            committed_validators: 0,
+        })
+    );
+}
+
+#[test]
+fn test_finalize_request_when_not_ended() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_answers(&mut contract, None, &yes_no_answers(10));
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_VALIDATOR_TIME)
+        .create();
+
+    let result = contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, FinalizeRequestResult::NotEnded);
+    assert_eq!(contract.get_decision_result(DEFAULT_REQUEST_ID.to_string()), None);
+    assert_eq!(get_logs().last().unwrap(), &format!("Request has not ended: {}", DEFAULT_REQUEST_ID));
+}
+
+#[test]
+fn test_finalize_request_twice() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_answers(&mut contract, None, &yes_no_answers(10));
+
+    finalize_default_request(&mut contract);
+    let result = contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, FinalizeRequestResult::AlreadyFinalized);
+    assert_eq!(get_logs().last().unwrap(), &format!("Request already finalized: {}", DEFAULT_REQUEST_ID));
+}
+
+#[test]
+fn test_finalize_request_when_not_registered() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let result = finalize_default_request(&mut contract);
+
+    assert_eq!(result, FinalizeRequestResult::RequestNotFound);
+}
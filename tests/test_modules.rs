@@ -0,0 +1,185 @@
+use serde_json::json;
+
+use common::constants::{DEFAULT_DEPOSIT_MINER, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, MINER_1, UNBONDING_TIME};
+use common::environment::Environment;
+use common::types::{Log, RequestParams};
+use common::utils::{assert_logs, get_account_for_miner, get_default_miner_account, get_owner_account, register_request, small_group_of_miners};
+
+use earthmind_rs::{CommitError, CommitMinerResult, CommitteeConfig, Config, Contract, Module, Participant, UpdateMinerModulesResult, WithdrawStakeResult};
+
+pub mod common;
+
+fn register_miner(contract: &mut Contract, miner: &str, modules: Vec<Module>) {
+    Environment::with_account(get_account_for_miner(miner))
+        .with_attached_deposit(DEFAULT_DEPOSIT_MINER)
+        .create();
+    contract.register_miner(modules);
+}
+
+#[test]
+fn test_request_defaults_to_the_first_module_of_the_protocol() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_request(
+        &mut contract,
+        RequestParams {
+            modules: vec![Module::TextPrompting, Module::ObjectRecognition],
+            ..RequestParams::default()
+        },
+    );
+
+    let summary = contract.get_request_summary(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(summary.module, Some(Module::TextPrompting));
+}
+
+#[test]
+#[should_panic(expected = "Module not supported by the protocol")]
+fn test_request_governance_decision_when_protocol_lacks_the_module() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    register_request(
+        &mut contract,
+        RequestParams {
+            module: Some(Module::ObjectRecognition),
+            ..RequestParams::default()
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "At least one module is required")]
+fn test_register_miner_without_modules() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    register_miner(&mut contract, MINER_1, vec![]);
+}
+
+#[test]
+fn test_commit_by_miner_when_miner_doesnt_support_the_module() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_miner(&mut contract, MINER_1, vec![Module::TextPrompting]);
+    register_request(
+        &mut contract,
+        RequestParams {
+            modules: vec![Module::TextPrompting, Module::ObjectRecognition],
+            module: Some(Module::ObjectRecognition),
+            ..RequestParams::default()
+        },
+    );
+
+    Environment::with_account(get_default_miner_account()).create();
+    let result = contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+
+    assert_eq!(result, CommitMinerResult::Fail(CommitError::ModuleNotSupported));
+    assert_logs(vec![Log::Message(format!("Miner doesn't support the module of the request: {}", MINER_1))]);
+}
+
+#[test]
+fn test_get_miners_by_module() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_miner(
+        &mut contract,
+        "miner2.near",
+        vec![Module::ObjectRecognition, Module::TextPrompting, Module::ObjectRecognition],
+    );
+    register_miner(&mut contract, "miner1.near", vec![Module::TextPrompting]);
+
+    assert_eq!(
+        contract.get_miners_by_module(Module::TextPrompting, None, None),
+        vec![get_account_for_miner("miner2.near"), get_account_for_miner("miner1.near")]
+    );
+    assert_eq!(
+        contract.get_miners_by_module(Module::ObjectRecognition, None, None),
+        vec![get_account_for_miner("miner2.near")]
+    );
+    assert_eq!(
+        contract.get_miner_modules(get_account_for_miner("miner2.near")),
+        vec![Module::ObjectRecognition, Module::TextPrompting]
+    );
+}
+
+#[test]
+fn test_update_miner_modules() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_miner(&mut contract, MINER_1, vec![Module::TextPrompting]);
+
+    let result = contract.update_miner_modules(vec![Module::ObjectRecognition]);
+
+    assert_eq!(result, UpdateMinerModulesResult::Success);
+    assert_eq!(contract.get_miner_modules(get_default_miner_account()), vec![Module::ObjectRecognition]);
+    assert!(contract.get_miners_by_module(Module::TextPrompting, None, None).is_empty());
+    assert_eq!(
+        contract.get_miners_by_module(Module::ObjectRecognition, None, None),
+        vec![get_default_miner_account()]
+    );
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "register_miner".to_string(),
+            data: vec![("miner", json![MINER_1]), ("modules", json![["TextPrompting"]])],
+        },
+        Log::Event {
+            event_name: "miner_modules_updated".to_string(),
+            data: vec![("miner", json![MINER_1]), ("modules", json![["ObjectRecognition"]])],
+        },
+    ]);
+}
+
+#[test]
+fn test_update_miner_modules_when_miner_is_not_registered() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_miner_account()).create();
+    let result = contract.update_miner_modules(vec![Module::TextPrompting]);
+
+    assert_eq!(result, UpdateMinerModulesResult::NotRegistered);
+    assert_logs(vec![Log::Message(format!("Miner not registered: {}", MINER_1))]);
+}
+
+#[test]
+fn test_withdraw_stake_removes_the_miner_from_its_modules() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_miner(&mut contract, MINER_1, vec![Module::TextPrompting]);
+    contract.request_unstake(Participant::Miner);
+
+    Environment::with_account(get_default_miner_account())
+        .with_block_timestamp(UNBONDING_TIME)
+        .create();
+    let result = contract.withdraw_stake(Participant::Miner);
+
+    assert_eq!(result, WithdrawStakeResult::Success);
+    assert!(contract.get_miner_modules(get_default_miner_account()).is_empty());
+    assert!(contract.get_miners_by_module(Module::TextPrompting, None, None).is_empty());
+}
+
+#[test]
+fn test_committee_is_sampled_among_the_miners_of_the_module() {
+    let mut contract = Contract::new(
+        get_owner_account(),
+        Config {
+            max_ranking_size: 3,
+            committee: Some(CommitteeConfig {
+                miners: 3,
+                validators: 1,
+                stake_weighted: false,
+            }),
+            ..Config::default()
+        },
+    );
+    register_miner(&mut contract, "miner1.near", vec![Module::ObjectRecognition]);
+    register_miner(&mut contract, "miner2.near", vec![Module::TextPrompting]);
+    register_miner(&mut contract, "miner3.near", vec![Module::TextPrompting, Module::ObjectRecognition]);
+    register_request(
+        &mut contract,
+        RequestParams {
+            modules: vec![Module::TextPrompting, Module::ObjectRecognition],
+            module: Some(Module::ObjectRecognition),
+            ..RequestParams::default()
+        },
+    );
+
+    let mut committee = contract.get_committee(DEFAULT_REQUEST_ID.to_string()).unwrap().miners;
+    committee.sort();
+
+    assert_eq!(committee, vec![small_group_of_miners()[0].clone(), small_group_of_miners()[2].clone()]);
+}
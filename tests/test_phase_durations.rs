@@ -0,0 +1,116 @@
+use common::constants::{
+    DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, DEFAULT_TIMESTAMP,
+};
+use common::environment::Environment;
+use common::utils::{default_culture, get_default_miner_account, get_default_protocol_account, get_owner_account};
+
+use earthmind_rs::{CommitError, CommitMinerResult, Config, Contract, Module, PhaseDurations, RegisterProtocolResult, RegisterRequestResult};
+
+pub mod common;
+
+const ONE_SECOND: u64 = 1_000_000_000;
+const ONE_HOUR: u64 = 60 * 60 * ONE_SECOND;
+
+const fn hours_profile() -> PhaseDurations {
+    PhaseDurations {
+        commit_miner: ONE_HOUR,
+        reveal_miner: ONE_HOUR,
+        commit_validator: ONE_HOUR,
+        reveal_validator: ONE_HOUR,
+    }
+}
+
+const fn seconds_profile() -> PhaseDurations {
+    PhaseDurations {
+        commit_miner: 5 * ONE_SECOND,
+        reveal_miner: 5 * ONE_SECOND,
+        commit_validator: 5 * ONE_SECOND,
+        reveal_validator: 5 * ONE_SECOND,
+    }
+}
+
+fn commit_default_miner_at(contract: &mut Contract, timestamp: u64) -> CommitMinerResult {
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+
+    Environment::with_account(miner).with_block_timestamp(timestamp).create();
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string())
+}
+
+#[test]
+fn test_request_uses_the_protocol_timing() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    let result = contract.register_protocol(default_culture(), modules, Some(hours_profile()));
+    assert_eq!(result, RegisterProtocolResult::Success);
+
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    // @dev With the default timing the commit phase would be over after two minutes
+    let result = commit_default_miner_at(&mut contract, DEFAULT_TIMESTAMP + 30 * 60 * ONE_SECOND);
+
+    assert_eq!(result, CommitMinerResult::Success);
+}
+
+#[test]
+fn test_request_overrides_the_protocol_timing() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, Some(hours_profile()));
+
+    let result = contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), Some(seconds_profile()), None, None, None, None);
+    assert_eq!(result, RegisterRequestResult::Success);
+
+    let result = commit_default_miner_at(&mut contract, DEFAULT_TIMESTAMP + 10 * ONE_SECOND);
+
+    assert_eq!(result, CommitMinerResult::Fail(CommitError::WrongStage));
+}
+
+#[test]
+#[should_panic(expected = "Phase duration out of bounds")]
+fn test_register_protocol_when_timing_is_out_of_bounds() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    let timing = PhaseDurations {
+        commit_miner: 0,
+        ..seconds_profile()
+    };
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, Some(timing));
+}
+
+#[test]
+#[should_panic(expected = "Phase duration out of bounds")]
+fn test_request_governance_decision_when_timing_is_out_of_bounds() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, None);
+
+    let timing = PhaseDurations {
+        reveal_validator: 30 * 24 * ONE_HOUR,
+        ..hours_profile()
+    };
+
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), Some(timing), None, None, None, None);
+}
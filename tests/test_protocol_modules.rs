@@ -0,0 +1,151 @@
+use serde_json::json;
+
+use common::constants::{ACCOUNT_1, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_REQUEST_ID};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{assert_logs, get_default_protocol_account, get_owner_account, register_protocol};
+
+use earthmind_rs::{AddModuleResult, Config, Contract, Module, RemoveModuleResult};
+
+pub mod common;
+
+#[test]
+fn test_add_module() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.add_module(Module::ObjectRecognition);
+
+    assert_eq!(result, AddModuleResult::Success);
+    assert_eq!(
+        contract.get_protocol_modules(get_default_protocol_account()),
+        vec![Module::TextPrompting, Module::ObjectRecognition]
+    );
+    assert_eq!(contract.get_protocol_version(get_default_protocol_account()), Some(2));
+
+    assert_logs(vec![Log::Event {
+        event_name: "module_added".to_string(),
+        data: vec![("account", json![ACCOUNT_1]), ("version", json![2]), ("module", json!["ObjectRecognition"])],
+    }]);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, Some(Module::ObjectRecognition));
+
+    let summary = contract.get_request_summary(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    assert_eq!(summary.module, Some(Module::ObjectRecognition));
+    assert_eq!(summary.protocol_version, 2);
+}
+
+#[test]
+fn test_add_module_when_already_supported() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.add_module(Module::TextPrompting);
+
+    assert_eq!(result, AddModuleResult::AlreadySupported);
+    assert_logs(vec![Log::Message("Module already supported: TextPrompting".to_string())]);
+    assert_eq!(contract.get_protocol_version(get_default_protocol_account()), Some(1));
+}
+
+#[test]
+fn test_add_module_when_protocol_is_not_registered() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.add_module(Module::TextPrompting);
+
+    assert_eq!(result, AddModuleResult::NotRegistered);
+    assert_logs(vec![Log::Message(format!("Protocol not registered: {}", ACCOUNT_1))]);
+}
+
+#[test]
+fn test_remove_module() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(
+        &mut contract,
+        &get_default_protocol_account(),
+        vec![Module::TextPrompting, Module::ObjectRecognition],
+    );
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.remove_module(Module::TextPrompting);
+
+    assert_eq!(result, RemoveModuleResult::Success);
+    assert_eq!(contract.get_protocol_modules(get_default_protocol_account()), vec![Module::ObjectRecognition]);
+    assert_eq!(contract.get_protocol_version(get_default_protocol_account()), Some(2));
+
+    assert_logs(vec![Log::Event {
+        event_name: "module_removed".to_string(),
+        data: vec![("account", json![ACCOUNT_1]), ("version", json![2]), ("module", json!["TextPrompting"])],
+    }]);
+}
+
+#[test]
+fn test_remove_module_when_not_supported() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(
+        &mut contract,
+        &get_default_protocol_account(),
+        vec![Module::TextPrompting, Module::ObjectRecognition],
+    );
+    contract.remove_module(Module::ObjectRecognition);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.remove_module(Module::ObjectRecognition);
+
+    assert_eq!(result, RemoveModuleResult::NotSupported);
+    assert_logs(vec![Log::Message("Module not supported: ObjectRecognition".to_string())]);
+}
+
+#[test]
+fn test_remove_module_when_it_is_the_last_one() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.remove_module(Module::TextPrompting);
+
+    assert_eq!(result, RemoveModuleResult::LastModule);
+    assert_logs(vec![Log::Message(format!("Protocol can't remove its last module: {}", ACCOUNT_1))]);
+    assert_eq!(contract.get_protocol_modules(get_default_protocol_account()), vec![Module::TextPrompting]);
+}
+
+#[test]
+fn test_requests_keep_the_module_after_it_is_removed() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(
+        &mut contract,
+        &get_default_protocol_account(),
+        vec![Module::TextPrompting, Module::ObjectRecognition],
+    );
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, Some(Module::ObjectRecognition));
+
+    Environment::with_account(get_default_protocol_account()).create();
+    contract.remove_module(Module::ObjectRecognition);
+
+    let summary = contract.get_request_summary(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    assert_eq!(summary.module, Some(Module::ObjectRecognition));
+    assert_eq!(summary.protocol_version, 1);
+}
+
+#[test]
+#[should_panic(expected = "Module not supported by the protocol")]
+fn test_request_governance_decision_for_a_removed_module() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(
+        &mut contract,
+        &get_default_protocol_account(),
+        vec![Module::TextPrompting, Module::ObjectRecognition],
+    );
+    contract.remove_module(Module::ObjectRecognition);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, Some(Module::ObjectRecognition));
+}
@@ -0,0 +1,172 @@
+use near_sdk::{test_utils::get_logs, AccountId};
+use serde_json::json;
+
+use common::constants::{ACCOUNT_1, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME};
+use common::environment::Environment;
+use common::types::{Log, RequestParams};
+use common::utils::{
+    assert_logs, get_account_for_miner, get_default_protocol_account, get_default_validator_account, get_owner_account, register_request,
+    setup_request_with_small_ranking, small_group_of_miners,
+};
+
+use earthmind_rs::{Config, Contract, RevealError, RevealValidatorResult, Votes};
+
+pub mod common;
+
+const MESSAGE: &str = "It's a cool NFT";
+
+#[test]
+fn test_request_governance_decision_with_ranking_size() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_request(
+        &mut contract,
+        RequestParams {
+            ranking_size: Some(3),
+            ..RequestParams::default()
+        },
+    );
+
+    let summary = contract.get_request_summary(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(summary.ranking_size, 3);
+}
+
+#[test]
+fn test_default_ranking_size_is_lowered_to_the_config_maximum() {
+    let mut contract = Contract::new(
+        get_owner_account(),
+        Config {
+            max_ranking_size: 4,
+            ..Config::default()
+        },
+    );
+    register_request(&mut contract, RequestParams::default());
+
+    let summary = contract.get_request_summary(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(summary.ranking_size, 4);
+}
+
+#[test]
+#[should_panic(expected = "Ranking size out of bounds")]
+fn test_request_governance_decision_when_ranking_size_is_above_the_config_maximum() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    register_request(
+        &mut contract,
+        RequestParams {
+            ranking_size: Some(11),
+            ..RequestParams::default()
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Ranking size out of bounds")]
+fn test_request_governance_decision_when_ranking_size_is_zero() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    register_request(
+        &mut contract,
+        RequestParams {
+            ranking_size: Some(0),
+            ..RequestParams::default()
+        },
+    );
+}
+
+#[test]
+fn test_reveal_by_validator_with_small_ranking() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let miners = small_group_of_miners();
+    setup_request_with_small_ranking(&mut contract, None, &miners);
+
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), miners.clone(), MESSAGE.to_string(), None);
+
+    assert_eq!(result, RevealValidatorResult::Success);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    let top_miners = contract.get_top_voters(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(top_miners, miners.into_iter().map(|miner| (miner, 1)).collect::<Vec<_>>());
+
+    let logs = get_logs();
+    assert!(logs[0].contains(r#""event":"top_miners""#));
+    assert!(logs[0].contains(r#""ranking_size":3"#));
+}
+
+#[test]
+fn test_reveal_by_validator_when_answer_is_longer_than_the_ranking() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_small_ranking(&mut contract, None, &small_group_of_miners());
+
+    let mut answer = small_group_of_miners();
+    answer.push(get_account_for_miner("miner4.near"));
+
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer, MESSAGE.to_string(), None);
+
+    assert_eq!(result, RevealValidatorResult::Fail(RevealError::InvalidAnswer));
+    assert_logs(vec![Log::Message("Invalid answer".to_string())]);
+}
+
+#[test]
+#[should_panic(expected = "Invalid answer")]
+fn test_hash_validator_answer_when_answer_is_longer_than_the_ranking() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_request(
+        &mut contract,
+        RequestParams {
+            ranking_size: Some(3),
+            ..RequestParams::default()
+        },
+    );
+
+    let mut answer = small_group_of_miners();
+    answer.push(get_account_for_miner("miner4.near"));
+
+    contract.hash_validator_answer(
+        DEFAULT_REQUEST_ID.to_string(),
+        get_default_validator_account(),
+        answer,
+        MESSAGE.to_string(),
+        None,
+    );
+}
+
+#[test]
+fn test_top_miners_event_reports_the_ranking_size() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_request(
+        &mut contract,
+        RequestParams {
+            ranking_size: Some(3),
+            ..RequestParams::default()
+        },
+    );
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.get_top_voters(DEFAULT_REQUEST_ID.to_string());
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "top_miners".to_string(),
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("ranking_size", json![3]),
+                ("top_miners", json![Vec::<(AccountId, Votes)>::new()]),
+            ],
+        },
+        Log::EventBatch {
+            event_name: "reward_distributed".to_string(),
+            data: vec![vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("account", json![ACCOUNT_1]),
+                ("amount", json![DEFAULT_DEPOSIT_PROTOCOL]),
+            ]],
+        },
+    ]);
+}
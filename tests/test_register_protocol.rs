@@ -1,12 +1,12 @@
 use near_sdk::NearToken;
 use serde_json::json;
 
-use common::constants::{ACCOUNT_1, ACCOUNT_2, DEFAULT_CULTURE, DEFAULT_DEPOSIT_PROTOCOL};
+use common::constants::{ACCOUNT_1, ACCOUNT_2, DEFAULT_DEPOSIT_PROTOCOL};
 use common::environment::Environment;
 use common::types::Log;
-use common::utils::{assert_logs, get_account_for_protocol, get_default_protocol_account};
+use common::utils::{assert_logs, default_culture, get_account_for_protocol, get_default_protocol_account, get_owner_account, get_transfers};
 
-use earthmind_rs::{Contract, Module, RegisterProtocolResult};
+use earthmind_rs::{Config, Contract, Module, RegisterProtocolResult};
 
 pub mod common;
 
@@ -18,10 +18,10 @@ fn test_register_protocol() {
         .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
         .create();
 
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    let result_1 = contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
+    let result_1 = contract.register_protocol(default_culture(), modules, None);
 
     assert_eq!(result_1, RegisterProtocolResult::Success);
     assert!(contract.is_protocol_registered(account_1));
@@ -41,10 +41,10 @@ fn test_register_multiple_protocols() {
         .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
         .create();
 
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    let result_1 = contract.register_protocol(DEFAULT_CULTURE.to_string(), modules.clone());
+    let result_1 = contract.register_protocol(default_culture(), modules.clone(), None);
 
     assert_eq!(result_1, RegisterProtocolResult::Success);
     assert!(contract.is_protocol_registered(account_1));
@@ -60,7 +60,7 @@ fn test_register_multiple_protocols() {
         .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
         .create();
 
-    let result_2 = contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
+    let result_2 = contract.register_protocol(default_culture(), modules, None);
 
     assert_eq!(result_2, RegisterProtocolResult::Success);
     assert!(contract.is_protocol_registered(account_2));
@@ -77,14 +77,15 @@ fn test_register_protocol_when_is_registered_returns_already_registered() {
 
     Environment::with_account(account_1).with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL).create();
 
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules.clone());
+    contract.register_protocol(default_culture(), modules.clone(), None);
 
-    let result = contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
+    let result = contract.register_protocol(default_culture(), modules, None);
 
     assert_eq!(result, RegisterProtocolResult::AlreadyRegistered);
+    assert_eq!(get_transfers(), vec![(get_default_protocol_account(), DEFAULT_DEPOSIT_PROTOCOL)]);
 
     assert_logs(vec![
         Log::Event {
@@ -103,10 +104,10 @@ fn test_register_protocol_when_deposit_is_less_min_stake() {
 
     Environment::with_account(acoount_1).with_attached_deposit(deposit).create();
 
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
+    contract.register_protocol(default_culture(), modules, None);
 }
 
 #[test]
@@ -117,17 +118,17 @@ fn test_is_protocol_registered() {
         .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
         .create();
 
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
+    contract.register_protocol(default_culture(), modules, None);
 
     assert!(contract.is_protocol_registered(account_1));
 }
 
 #[test]
 fn test_is_protocol_registered_when_not_registered() {
-    let contract = Contract::new();
+    let contract = Contract::new(get_owner_account(), Config::default());
 
     let account_1: near_sdk::AccountId = get_default_protocol_account();
 
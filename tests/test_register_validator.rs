@@ -4,9 +4,9 @@ use serde_json::json;
 use common::constants::{DEFAULT_DEPOSIT_VALIDATOR, VALIDATOR_1, VALIDATOR_2};
 use common::environment::Environment;
 use common::types::Log;
-use common::utils::{assert_logs, get_account_for_validator, get_default_validator_account};
+use common::utils::{assert_logs, get_account_for_validator, get_default_validator_account, get_owner_account, get_transfers};
 
-use earthmind_rs::{Contract, RegisterValidatorResult};
+use earthmind_rs::{Config, Contract, RegisterValidatorResult};
 
 pub mod common;
 
@@ -18,7 +18,7 @@ fn test_register_validator() {
         .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
         .create();
 
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
     let result_1 = contract.register_validator();
     assert_eq!(result_1, RegisterValidatorResult::Success);
     assert!(contract.is_validator_registered(validator));
@@ -38,7 +38,7 @@ fn test_register_multiple_validators() {
         .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
         .create();
 
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     let result_1 = contract.register_validator();
     assert_eq!(result_1, RegisterValidatorResult::Success);
@@ -72,11 +72,12 @@ fn test_register_validator_when_is_registered_returns_already_registered() {
 
     Environment::with_account(validator).with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR).create();
 
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
     contract.register_validator();
 
     let result = contract.register_validator();
     assert_eq!(result, RegisterValidatorResult::AlreadyRegistered);
+    assert_eq!(get_transfers(), vec![(get_default_validator_account(), DEFAULT_DEPOSIT_VALIDATOR)]);
 
     assert_logs(vec![
         Log::Event {
@@ -93,7 +94,7 @@ fn test_register_validator_when_deposit_is_less_min_stake() {
     let validator = get_default_validator_account();
     Environment::with_account(validator).create();
 
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     contract.register_validator();
 }
@@ -105,7 +106,7 @@ fn test_is_validator_registered() {
     Environment::with_account(validator.clone())
         .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
         .create();
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
     contract.register_validator();
 
     assert!(contract.is_validator_registered(validator));
@@ -113,7 +114,7 @@ fn test_is_validator_registered() {
 
 #[test]
 fn test_is_validator_registered_when_not_registered() {
-    let contract = Contract::new();
+    let contract = Contract::new(get_owner_account(), Config::default());
     let validator: AccountId = get_default_validator_account();
 
     assert!(!contract.is_validator_registered(validator));
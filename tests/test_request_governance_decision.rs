@@ -2,19 +2,22 @@ use near_workspaces::AccountId;
 use serde_json::json;
 
 use common::constants::{
-    DEFAULT_CULTURE, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER,
+    DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER, REVEAL_TOPTEN_TIME,
 };
 use common::environment::Environment;
 use common::types::Log;
-use common::utils::{assert_logs, generate_validator_answer, get_default_miner_account, get_default_protocol_account, get_default_validator_account};
+use common::utils::{
+    assert_logs, default_culture, generate_validator_answer, get_default_miner_account, get_default_protocol_account, get_default_validator_account,
+    get_owner_account, get_request_id,
+};
 
-use earthmind_rs::{Contract, Module, RegisterRequestResult};
+use earthmind_rs::{hash_miner_commitment, Answer, AnswerRef, Config, Contract, Module, RegisterRequestResult};
 
 pub mod common;
 
 #[test]
-fn test_request_governance_decision_when_is_registered_returns_already_registered() {
-    let mut contract = Contract::new();
+fn test_request_governance_decision_when_the_question_is_repeated() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     let protocol = get_default_protocol_account();
     Environment::with_account(protocol.clone())
@@ -22,74 +25,186 @@ fn test_request_governance_decision_when_is_registered_returns_already_registere
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
+    contract.register_protocol(default_culture(), modules, None);
+
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
 
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    let result = contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
 
-    let result = contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    assert_eq!(result, RegisterRequestResult::Success);
 
-    assert_eq!(result, RegisterRequestResult::AlreadyRegistered);
+    let repeated_request_id = get_request_id(&protocol, DEFAULT_MESSAGE_TO_REQUEST, 1);
+    assert_ne!(repeated_request_id, DEFAULT_REQUEST_ID);
+    assert_eq!(contract.get_request_nonce(protocol.clone()), 2);
+    assert_eq!(contract.get_request_id_by_nonce(protocol.clone(), 0), Some(DEFAULT_REQUEST_ID.to_string()));
+    assert_eq!(contract.get_request_id_by_nonce(protocol.clone(), 1), Some(repeated_request_id.clone()));
+    assert_eq!(contract.get_request_id_by_nonce(protocol, 2), None);
 
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
-            data: vec![("account", json![protocol])],
+            data: vec![("account", json![get_default_protocol_account()])],
+        },
+        Log::Event {
+            event_name: "register_request".to_string(),
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![repeated_request_id]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
-        Log::Message("Attempted to register an already registered request: 73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b".to_string()),
     ]);
 }
 
+#[test]
+fn test_request_nonce_of_an_unknown_protocol() {
+    let contract = Contract::new(get_owner_account(), Config::default());
+
+    assert_eq!(contract.get_request_nonce(get_default_protocol_account()), 0);
+    assert_eq!(contract.get_request_id_by_nonce(get_default_protocol_account(), 0), None);
+}
+
+#[test]
+fn test_request_nonce_is_kept_after_deregistering() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let protocol = get_default_protocol_account();
+    Environment::with_account(protocol.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(default_culture(), vec![Module::TextPrompting], None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    Environment::with_account(protocol.clone()).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+    contract.deregister_protocol();
+
+    Environment::with_account(protocol.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(default_culture(), vec![Module::TextPrompting], None);
+    let result = contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    assert_eq!(result, RegisterRequestResult::Success);
+    assert_eq!(
+        contract.get_request_id_by_nonce(protocol.clone(), 1),
+        Some(get_request_id(&protocol, DEFAULT_MESSAGE_TO_REQUEST, 1))
+    );
+}
+
 // Hash miner answer
 
 #[test]
 fn test_hash_miner_answer() {
-    let contract = Contract::new();
+    let contract = Contract::new(get_owner_account(), Config::default());
 
-    let miner = get_default_miner_account();
-    Environment::with_account(miner).create();
+    // @dev The miner is explicit, the caller doesn't matter
+    Environment::with_account(get_default_protocol_account()).create();
 
     let request_id = DEFAULT_REQUEST_ID.to_string();
-    let answer = true;
+    let answer = Answer::YesNo(true);
     let message = "It's a cool NFT".to_string();
 
-    let result = contract.hash_miner_answer(request_id, answer, message);
+    let result = contract.hash_miner_answer(request_id, get_default_miner_account(), answer, message, None);
 
     assert_eq!(result, DEFAULT_MINER_ANSWER);
 }
 
+#[test]
+fn test_hash_miner_answer_with_salt() {
+    let contract = Contract::new(get_owner_account(), Config::default());
+    let miner = get_default_miner_account();
+    let salt = "6c2bf8a40d1e79b3a5f0e4c8d2b71a93".to_string();
+
+    let result = contract.hash_miner_answer(
+        DEFAULT_REQUEST_ID.to_string(),
+        miner.clone(),
+        Answer::Choice(2),
+        "It's a cool NFT".to_string(),
+        Some(salt.clone()),
+    );
+
+    assert_eq!(
+        result,
+        hash_miner_commitment(DEFAULT_REQUEST_ID, miner.as_str(), AnswerRef::Choice(2), "It's a cool NFT", &salt)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid answer")]
+fn test_hash_miner_answer_when_legacy_answer_is_not_yes_or_no() {
+    let contract = Contract::new(get_owner_account(), Config::default());
+
+    contract.hash_miner_answer(
+        DEFAULT_REQUEST_ID.to_string(),
+        get_default_miner_account(),
+        Answer::Score(10),
+        "It's a cool NFT".to_string(),
+        None,
+    );
+}
+
 // Hash validator answer
+
+// @dev The size of the answer is checked against the ranking size of the request
+fn register_default_request(contract: &mut Contract) {
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(default_culture(), vec![Module::TextPrompting], None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+}
+
 #[test]
 fn test_hash_validator_answer() {
-    let contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_default_request(&mut contract);
 
     let validator = get_default_validator_account();
-    Environment::with_account(validator).create();
 
     let request_id = DEFAULT_REQUEST_ID.to_string();
     let answer = generate_validator_answer();
     let message = "It's a cool NFT".to_string();
 
-    let result = contract.hash_validator_answer(request_id, answer, message);
+    let result = contract.hash_validator_answer(request_id, validator, answer, message, None);
 
     assert_eq!(result, DEFAULT_VALIDATOR_ANSWER);
 }
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "Invalid answer")]
 fn test_hash_validator_answer_when_answer_is_not_complete() {
-    let contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_default_request(&mut contract);
 
     let validator = get_default_validator_account();
-    Environment::with_account(validator).create();
 
     let request_id = DEFAULT_REQUEST_ID.to_string();
     let answer = generate_validator_answer();
     let answer: Vec<AccountId> = answer[0..answer.len() - 1].to_vec();
     let message = "It's a cool NFT".to_string();
 
-    contract.hash_validator_answer(request_id, answer, message);
+    contract.hash_validator_answer(request_id, validator, answer, message, None);
+}
+
+#[test]
+#[should_panic(expected = "Request not found")]
+fn test_hash_validator_answer_when_request_is_not_registered() {
+    let contract = Contract::new(get_owner_account(), Config::default());
+
+    contract.hash_validator_answer(
+        DEFAULT_REQUEST_ID.to_string(),
+        get_default_validator_account(),
+        generate_validator_answer(),
+        "It's a cool NFT".to_string(),
+        None,
+    );
 }
@@ -1,12 +1,11 @@
 use common::constants::{
-    DEFAULT_CULTURE, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, MINER_1, MINER_2,
-    REVEAL_MINER_TIME,
+    DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, MINER_1, MINER_2, REVEAL_MINER_TIME,
 };
 use common::environment::Environment;
 use common::types::Log;
-use common::utils::{assert_logs, get_account_for_miner, get_default_miner_account, get_default_protocol_account};
+use common::utils::{assert_logs, default_culture, get_account_for_miner, get_default_miner_account, get_default_protocol_account, get_owner_account};
 
-use earthmind_rs::{Contract, Module, RevealMinerResult};
+use earthmind_rs::{Answer, Config, Contract, Module, RevealError, RevealMinerResult};
 
 use serde_json::json;
 
@@ -14,7 +13,7 @@ pub mod common;
 
 #[test]
 fn test_reveal_by_miner() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -23,8 +22,8 @@ fn test_reveal_by_miner() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -32,20 +31,24 @@ fn test_reveal_by_miner() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
     let miner = get_default_miner_account();
 
     Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
-    contract.register_miner();
+    contract.register_miner(vec![Module::TextPrompting]);
     contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
 
     assert_logs(vec![
         Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![MINER_1])],
+            data: vec![("miner", json![MINER_1]), ("modules", json![["TextPrompting"]])],
         },
         Log::Event {
             event_name: "commit_miner".to_string(),
@@ -58,7 +61,7 @@ fn test_reveal_by_miner() {
     let answer = true;
     let message = "It's a cool NFT";
 
-    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.to_string(), None);
 
     assert_eq!(result, RevealMinerResult::Success);
 
@@ -66,7 +69,7 @@ fn test_reveal_by_miner() {
         event_name: "reveal_miner".to_string(),
         data: vec![
             ("request_id", json![DEFAULT_REQUEST_ID]),
-            ("answer", json![answer]),
+            ("answer", json![{ "YesNo": answer }]),
             ("message", json![message]),
         ],
     }]);
@@ -74,7 +77,7 @@ fn test_reveal_by_miner() {
 
 #[test]
 fn test_reveal_by_miner_when_miner_is_not_registered() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -83,8 +86,8 @@ fn test_reveal_by_miner_when_miner_is_not_registered() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -92,21 +95,25 @@ fn test_reveal_by_miner_when_miner_is_not_registered() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
     let miner_1 = get_default_miner_account();
 
     Environment::with_account(miner_1).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
-    contract.register_miner();
+    contract.register_miner(vec![Module::TextPrompting]);
 
     contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
 
     assert_logs(vec![
         Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![MINER_1])],
+            data: vec![("miner", json![MINER_1]), ("modules", json![["TextPrompting"]])],
         },
         Log::Event {
             event_name: "commit_miner".to_string(),
@@ -120,16 +127,16 @@ fn test_reveal_by_miner_when_miner_is_not_registered() {
     let answer = true;
     let message = "It's a cool NFT";
 
-    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.to_string(), None);
 
-    assert_eq!(result, RevealMinerResult::Fail);
+    assert_eq!(result, RevealMinerResult::Fail(RevealError::NotRegistered));
 
     assert_logs(vec![Log::Message("Miner not registered: miner2.near".to_string())]);
 }
 
 #[test]
 fn test_reveal_by_miner_when_request_is_not_registered() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -138,8 +145,8 @@ fn test_reveal_by_miner_when_request_is_not_registered() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
 
     assert_logs(vec![
         Log::Event {
@@ -148,7 +155,11 @@ fn test_reveal_by_miner_when_request_is_not_registered() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -156,7 +167,7 @@ fn test_reveal_by_miner_when_request_is_not_registered() {
 
     Environment::with_account(miner_1).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
 
-    contract.register_miner();
+    contract.register_miner(vec![Module::TextPrompting]);
 
     contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
 
@@ -164,14 +175,14 @@ fn test_reveal_by_miner_when_request_is_not_registered() {
     let answer = true;
     let message = "It's a cool NFT".to_string();
 
-    let result = contract.reveal_by_miner(fail_request_id, answer, message);
+    let result = contract.reveal_by_miner(fail_request_id, Answer::YesNo(answer), message, None);
 
-    assert_eq!(result, RevealMinerResult::Fail);
+    assert_eq!(result, RevealMinerResult::Fail(RevealError::RequestNotFound));
 
     assert_logs(vec![
         Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![MINER_1])],
+            data: vec![("miner", json![MINER_1]), ("modules", json![["TextPrompting"]])],
         },
         Log::Event {
             event_name: "commit_miner".to_string(),
@@ -183,7 +194,7 @@ fn test_reveal_by_miner_when_request_is_not_registered() {
 
 #[test]
 fn test_reveal_by_miner_when_proposal_is_already_reveal() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -192,8 +203,8 @@ fn test_reveal_by_miner_when_proposal_is_already_reveal() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
 
     assert_logs(vec![
         Log::Event {
@@ -202,7 +213,11 @@ fn test_reveal_by_miner_when_proposal_is_already_reveal() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -210,14 +225,14 @@ fn test_reveal_by_miner_when_proposal_is_already_reveal() {
 
     Environment::with_account(miner_1.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
 
-    contract.register_miner();
+    contract.register_miner(vec![Module::TextPrompting]);
 
     contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
 
     assert_logs(vec![
         Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![MINER_1])],
+            data: vec![("miner", json![MINER_1]), ("modules", json![["TextPrompting"]])],
         },
         Log::Event {
             event_name: "commit_miner".to_string(),
@@ -230,18 +245,18 @@ fn test_reveal_by_miner_when_proposal_is_already_reveal() {
     let answer = true;
     let message = "It's a cool NFT".to_string();
 
-    contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.clone());
+    contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.clone(), None);
 
-    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.clone());
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.clone(), None);
 
-    assert_eq!(result, RevealMinerResult::Fail);
+    assert_eq!(result, RevealMinerResult::Fail(RevealError::AlreadyRevealed));
 
     assert_logs(vec![
         Log::Event {
             event_name: "reveal_miner".to_string(),
             data: vec![
                 ("request_id", json![DEFAULT_REQUEST_ID]),
-                ("answer", json![answer]),
+                ("answer", json![{ "YesNo": answer }]),
                 ("message", json![message]),
             ],
         },
@@ -251,7 +266,7 @@ fn test_reveal_by_miner_when_proposal_is_already_reveal() {
 
 #[test]
 fn test_reveal_by_miner_when_answer_not_equal() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -260,8 +275,8 @@ fn test_reveal_by_miner_when_answer_not_equal() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
 
     assert_logs(vec![
         Log::Event {
@@ -270,7 +285,11 @@ fn test_reveal_by_miner_when_answer_not_equal() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -278,14 +297,14 @@ fn test_reveal_by_miner_when_answer_not_equal() {
 
     Environment::with_account(miner_1.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
 
-    contract.register_miner();
+    contract.register_miner(vec![Module::TextPrompting]);
 
     contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
 
     assert_logs(vec![
         Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![MINER_1])],
+            data: vec![("miner", json![MINER_1]), ("modules", json![["TextPrompting"]])],
         },
         Log::Event {
             event_name: "commit_miner".to_string(),
@@ -297,8 +316,33 @@ fn test_reveal_by_miner_when_answer_not_equal() {
 
     let answer = false;
     let message = "It's a cool NFT";
-    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.to_string(), None);
 
-    assert_eq!(result, RevealMinerResult::Fail);
+    assert_eq!(result, RevealMinerResult::Fail(RevealError::HashMismatch));
     assert_logs(vec![Log::Message("Answer don't match".to_string())]);
 }
+
+#[test]
+fn test_reveal_by_miner_when_request_is_not_at_reveal_stage() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    // @dev Protocol register to earthmind protocol and request a governance decision
+    let protocol = get_default_protocol_account();
+    Environment::with_account(protocol).with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL).create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+
+    contract.register_miner(vec![Module::TextPrompting]);
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+
+    // @dev Still at the commit stage
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(true), "It's a cool NFT".to_string(), None);
+
+    assert_eq!(result, RevealMinerResult::Fail(RevealError::WrongStage));
+    assert_eq!(serde_json::to_value(&result).unwrap(), json!({ "Fail": "WrongStage" }));
+}
@@ -2,23 +2,23 @@ use near_sdk::AccountId;
 use serde_json::json;
 
 use common::constants::{
-    COMMIT_VALIDATOR_TIME, DEFAULT_CULTURE, DEFAULT_DEPOSIT, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR,
-    DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER, REVEAL_MINER_TIME, REVEAL_VALIDATOR_TIME, VALIDATOR_1, VALIDATOR_2,
+    COMMIT_VALIDATOR_TIME, DEFAULT_DEPOSIT, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MESSAGE_TO_REQUEST,
+    DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER, REVEAL_MINER_TIME, REVEAL_VALIDATOR_TIME, VALIDATOR_1, VALIDATOR_2,
 };
 use common::environment::Environment;
 use common::types::Log;
 use common::utils::{
-    assert_logs, default_miners_commit_answer, generate_validator_answer, get_account_for_validator, get_default_protocol_account,
-    get_default_validator_account, group_registered_miners,
+    assert_logs, default_culture, default_miners_commit_answer, generate_validator_answer, get_account_for_validator, get_default_protocol_account,
+    get_default_validator_account, get_owner_account, group_registered_miners,
 };
 
-use earthmind_rs::{Contract, Module, RevealMinerResult, RevealValidatorResult};
+use earthmind_rs::{Answer, Config, Contract, Module, RevealError, RevealMinerResult, RevealValidatorResult};
 
 pub mod common;
 
 #[test]
 fn test_reveal_by_validator() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -27,8 +27,8 @@ fn test_reveal_by_validator() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -36,7 +36,11 @@ fn test_reveal_by_validator() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -57,13 +61,13 @@ fn test_reveal_by_validator() {
 
     for (index, miners) in registered_miners.clone().into_iter().enumerate() {
         Environment::with_account(miners.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
-        contract.register_miner();
+        contract.register_miner(vec![Module::TextPrompting]);
         contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), default_answer_miners[index].clone());
 
         assert_logs(vec![
             Log::Event {
                 event_name: "register_miner".to_string(),
-                data: vec![("miner", json![miners])],
+                data: vec![("miner", json![miners]), ("modules", json![["TextPrompting"]])],
             },
             Log::Event {
                 event_name: "commit_miner".to_string(),
@@ -79,13 +83,13 @@ fn test_reveal_by_validator() {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
         let answer = true;
         let message = "It's a cool NFT";
-        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.to_string(), None);
         assert_eq!(result, RevealMinerResult::Success);
         assert_logs(vec![Log::Event {
             event_name: "reveal_miner".to_string(),
             data: vec![
                 ("request_id", json![DEFAULT_REQUEST_ID]),
-                ("answer", json![answer]),
+                ("answer", json![{ "YesNo": answer }]),
                 ("message", json![message]),
             ],
         }]);
@@ -106,7 +110,7 @@ fn test_reveal_by_validator() {
 
     let answer = generate_validator_answer();
     let message = "It's a cool NFT".to_string();
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone());
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone(), None);
 
     assert_eq!(result, RevealValidatorResult::Success);
     assert_logs(vec![Log::Event {
@@ -121,7 +125,7 @@ fn test_reveal_by_validator() {
 
 #[test]
 fn test_reveal_by_validator_when_miner_dont_have_a_commit_answer() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -130,8 +134,8 @@ fn test_reveal_by_validator_when_miner_dont_have_a_commit_answer() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -139,7 +143,11 @@ fn test_reveal_by_validator_when_miner_dont_have_a_commit_answer() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -147,10 +155,10 @@ fn test_reveal_by_validator_when_miner_dont_have_a_commit_answer() {
 
     for miners in registered_miners {
         Environment::with_account(miners.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
-        contract.register_miner();
+        contract.register_miner(vec![Module::TextPrompting]);
         assert_logs(vec![Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![miners])],
+            data: vec![("miner", json![miners]), ("modules", json![["TextPrompting"]])],
         }]);
     }
 
@@ -182,15 +190,15 @@ fn test_reveal_by_validator_when_miner_dont_have_a_commit_answer() {
 
     let answer = generate_validator_answer();
     let message = "It's a cool NFT".to_string();
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer, message);
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer, message, None);
 
-    assert_eq!(result, RevealValidatorResult::Fail);
+    assert_eq!(result, RevealValidatorResult::Fail(RevealError::MinerNotCommitted));
     assert_logs(vec![Log::Message("Account not registered a commit: miner1.near".to_string())]);
 }
 
 #[test]
 fn test_reveal_by_validator_when_miner_have_a_commit_answer_but_not_revealed() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -199,8 +207,8 @@ fn test_reveal_by_validator_when_miner_have_a_commit_answer_but_not_revealed() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -208,7 +216,11 @@ fn test_reveal_by_validator_when_miner_have_a_commit_answer_but_not_revealed() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -229,13 +241,13 @@ fn test_reveal_by_validator_when_miner_have_a_commit_answer_but_not_revealed() {
 
     for (index, miners) in registered_miners.clone().into_iter().enumerate() {
         Environment::with_account(miners.clone()).with_attached_deposit(DEFAULT_DEPOSIT).create();
-        contract.register_miner();
+        contract.register_miner(vec![Module::TextPrompting]);
         contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), default_answer_miners[index].clone());
 
         assert_logs(vec![
             Log::Event {
                 event_name: "register_miner".to_string(),
-                data: vec![("miner", json![miners])],
+                data: vec![("miner", json![miners]), ("modules", json![["TextPrompting"]])],
             },
             Log::Event {
                 event_name: "commit_miner".to_string(),
@@ -255,13 +267,13 @@ fn test_reveal_by_validator_when_miner_have_a_commit_answer_but_not_revealed() {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
         let answer = true;
         let message = "It's a cool NFT";
-        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.to_string(), None);
         assert_eq!(result, RevealMinerResult::Success);
         assert_logs(vec![Log::Event {
             event_name: "reveal_miner".to_string(),
             data: vec![
                 ("request_id", json![DEFAULT_REQUEST_ID]),
-                ("answer", json![answer]),
+                ("answer", json![{ "YesNo": answer }]),
                 ("message", json![message]),
             ],
         }]);
@@ -282,15 +294,15 @@ fn test_reveal_by_validator_when_miner_have_a_commit_answer_but_not_revealed() {
 
     let answer = generate_validator_answer();
     let message = "It's a cool NFT".to_string();
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer, message);
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer, message, None);
 
-    assert_eq!(result, RevealValidatorResult::Fail);
+    assert_eq!(result, RevealValidatorResult::Fail(RevealError::MinerNotRevealed));
     assert_logs(vec![Log::Message("Commit by miner not revealed: miner10.near".to_string())]);
 }
 
 #[test]
 fn test_reveal_by_validator_when_validator_is_not_registered() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -299,8 +311,8 @@ fn test_reveal_by_validator_when_validator_is_not_registered() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -308,7 +320,11 @@ fn test_reveal_by_validator_when_validator_is_not_registered() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -340,16 +356,16 @@ fn test_reveal_by_validator_when_validator_is_not_registered() {
 
     let answer = generate_validator_answer();
     let message = "It's a cool NFT".to_string();
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer, message);
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer, message, None);
 
-    assert_eq!(result, RevealValidatorResult::Fail);
+    assert_eq!(result, RevealValidatorResult::Fail(RevealError::NotRegistered));
 
     assert_logs(vec![Log::Message("Validator is not registered: validator2.near".to_string())]);
 }
 
 #[test]
 fn test_reveal_by_validator_when_request_is_not_registered() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -358,8 +374,8 @@ fn test_reveal_by_validator_when_request_is_not_registered() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -367,7 +383,11 @@ fn test_reveal_by_validator_when_request_is_not_registered() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -375,10 +395,10 @@ fn test_reveal_by_validator_when_request_is_not_registered() {
 
     for miners in registered_miners {
         Environment::with_account(miners.clone()).with_attached_deposit(DEFAULT_DEPOSIT).create();
-        contract.register_miner();
+        contract.register_miner(vec![Module::TextPrompting]);
         assert_logs(vec![Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![miners])],
+            data: vec![("miner", json![miners]), ("modules", json![["TextPrompting"]])],
         }]);
     }
 
@@ -411,9 +431,9 @@ fn test_reveal_by_validator_when_request_is_not_registered() {
     let answer: Vec<AccountId> = generate_validator_answer();
     let message = "It's a cool NFT".to_string();
 
-    let result = contract.reveal_by_validator(request_id_unregistered, answer, message);
+    let result = contract.reveal_by_validator(request_id_unregistered, answer, message, None);
 
-    assert_eq!(result, RevealValidatorResult::Fail);
+    assert_eq!(result, RevealValidatorResult::Fail(RevealError::RequestNotFound));
 
     assert_logs(vec![Log::Message(
         "Request is not registered: 0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae725".to_string(),
@@ -422,7 +442,7 @@ fn test_reveal_by_validator_when_request_is_not_registered() {
 
 #[test]
 fn test_reveal_by_validator_when_proposal_is_already_reveal() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -431,8 +451,8 @@ fn test_reveal_by_validator_when_proposal_is_already_reveal() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -440,7 +460,11 @@ fn test_reveal_by_validator_when_proposal_is_already_reveal() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -462,13 +486,13 @@ fn test_reveal_by_validator_when_proposal_is_already_reveal() {
 
     for (index, miners) in registered_miners.clone().into_iter().enumerate() {
         Environment::with_account(miners.clone()).with_attached_deposit(DEFAULT_DEPOSIT).create();
-        contract.register_miner();
+        contract.register_miner(vec![Module::TextPrompting]);
         contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), default_answer_miners[index].clone());
 
         assert_logs(vec![
             Log::Event {
                 event_name: "register_miner".to_string(),
-                data: vec![("miner", json![miners])],
+                data: vec![("miner", json![miners]), ("modules", json![["TextPrompting"]])],
             },
             Log::Event {
                 event_name: "commit_miner".to_string(),
@@ -484,13 +508,13 @@ fn test_reveal_by_validator_when_proposal_is_already_reveal() {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
         let answer = true;
         let message = "It's a cool NFT";
-        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.to_string(), None);
         assert_eq!(result, RevealMinerResult::Success);
         assert_logs(vec![Log::Event {
             event_name: "reveal_miner".to_string(),
             data: vec![
                 ("request_id", json![DEFAULT_REQUEST_ID]),
-                ("answer", json![answer]),
+                ("answer", json![{ "YesNo": answer }]),
                 ("message", json![message]),
             ],
         }]);
@@ -511,11 +535,11 @@ fn test_reveal_by_validator_when_proposal_is_already_reveal() {
 
     let answer = generate_validator_answer();
     let message = "It's a cool NFT".to_string();
-    contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone());
+    contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone(), None);
 
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone());
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone(), None);
 
-    assert_eq!(result, RevealValidatorResult::Fail);
+    assert_eq!(result, RevealValidatorResult::Fail(RevealError::AlreadyRevealed));
 
     assert_logs(vec![
         Log::Event {
@@ -532,7 +556,7 @@ fn test_reveal_by_validator_when_proposal_is_already_reveal() {
 
 #[test]
 fn test_reveal_by_validator_when_answer_not_equal() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -541,8 +565,8 @@ fn test_reveal_by_validator_when_answer_not_equal() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -550,7 +574,11 @@ fn test_reveal_by_validator_when_answer_not_equal() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -572,13 +600,13 @@ fn test_reveal_by_validator_when_answer_not_equal() {
 
     for (index, miners) in registered_miners.clone().into_iter().enumerate() {
         Environment::with_account(miners.clone()).with_attached_deposit(DEFAULT_DEPOSIT).create();
-        contract.register_miner();
+        contract.register_miner(vec![Module::TextPrompting]);
         contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), default_answer_miners[index].clone());
 
         assert_logs(vec![
             Log::Event {
                 event_name: "register_miner".to_string(),
-                data: vec![("miner", json![miners])],
+                data: vec![("miner", json![miners]), ("modules", json![["TextPrompting"]])],
             },
             Log::Event {
                 event_name: "commit_miner".to_string(),
@@ -594,13 +622,13 @@ fn test_reveal_by_validator_when_answer_not_equal() {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
         let answer = true;
         let message = "It's a cool NFT";
-        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.to_string(), None);
         assert_eq!(result, RevealMinerResult::Success);
         assert_logs(vec![Log::Event {
             event_name: "reveal_miner".to_string(),
             data: vec![
                 ("request_id", json![DEFAULT_REQUEST_ID]),
-                ("answer", json![answer]),
+                ("answer", json![{ "YesNo": answer }]),
                 ("message", json![message]),
             ],
         }]);
@@ -609,14 +637,14 @@ fn test_reveal_by_validator_when_answer_not_equal() {
     let extra_miner: AccountId = "miner11.near".parse().unwrap();
     let extra_miner_answer = "b574e5145b78602616f316e59a3556819d249c9297dfaab7938875bbb77c18d9".to_string();
     Environment::with_account(extra_miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT).create();
-    contract.register_miner();
+    contract.register_miner(vec![Module::TextPrompting]);
 
     contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), extra_miner_answer.clone());
 
     assert_logs(vec![
         Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![extra_miner])],
+            data: vec![("miner", json![extra_miner]), ("modules", json![["TextPrompting"]])],
         },
         Log::Event {
             event_name: "commit_miner".to_string(),
@@ -627,13 +655,13 @@ fn test_reveal_by_validator_when_answer_not_equal() {
 
     let answer = true;
     let message = "It's a cool NFT";
-    contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+    contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.to_string(), None);
 
     assert_logs(vec![Log::Event {
         event_name: "reveal_miner".to_string(),
         data: vec![
             ("request_id", json![DEFAULT_REQUEST_ID]),
-            ("answer", json![answer]),
+            ("answer", json![{ "YesNo": answer }]),
             ("message", json![message]),
         ],
     }]);
@@ -654,16 +682,16 @@ fn test_reveal_by_validator_when_answer_not_equal() {
     let mut answer: Vec<AccountId> = generate_validator_answer();
     answer[9] = "miner11.near".parse().unwrap();
     let message = "It's a cool NFT".to_string();
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message);
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message, None);
 
-    assert_eq!(result, RevealValidatorResult::Fail);
+    assert_eq!(result, RevealValidatorResult::Fail(RevealError::HashMismatch));
 
     assert_logs(vec![Log::Message("Answer don't match".to_string())]);
 }
 
 #[test]
 fn test_reveal_by_validator_when_vote_for_miner_not_registered() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -672,8 +700,8 @@ fn test_reveal_by_validator_when_vote_for_miner_not_registered() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -681,7 +709,11 @@ fn test_reveal_by_validator_when_vote_for_miner_not_registered() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -690,10 +722,10 @@ fn test_reveal_by_validator_when_vote_for_miner_not_registered() {
     for miners in registered_miners {
         Environment::with_account(miners.clone()).with_attached_deposit(DEFAULT_DEPOSIT).create();
 
-        contract.register_miner();
+        contract.register_miner(vec![Module::TextPrompting]);
         assert_logs(vec![Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![miners])],
+            data: vec![("miner", json![miners]), ("modules", json![["TextPrompting"]])],
         }]);
     }
 
@@ -726,16 +758,16 @@ fn test_reveal_by_validator_when_vote_for_miner_not_registered() {
     let mut answer: Vec<AccountId> = generate_validator_answer();
     answer[9] = "miner12.near".parse().unwrap();
     let message = "It's a cool NFT".to_string();
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message);
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message, None);
 
-    assert_eq!(result, RevealValidatorResult::Fail);
+    assert_eq!(result, RevealValidatorResult::Fail(RevealError::MinerNotRegistered));
 
     assert_logs(vec![Log::Message("Account not registered as miner: miner12.near".to_string())]);
 }
 
 #[test]
 fn test_reveal_by_validator_when_miner_is_duplicated() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -744,8 +776,8 @@ fn test_reveal_by_validator_when_miner_is_duplicated() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -753,7 +785,11 @@ fn test_reveal_by_validator_when_miner_is_duplicated() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -761,10 +797,10 @@ fn test_reveal_by_validator_when_miner_is_duplicated() {
 
     for miners in registered_miners {
         Environment::with_account(miners.clone()).with_attached_deposit(DEFAULT_DEPOSIT).create();
-        contract.register_miner();
+        contract.register_miner(vec![Module::TextPrompting]);
         assert_logs(vec![Log::Event {
             event_name: "register_miner".to_string(),
-            data: vec![("miner", json![miners])],
+            data: vec![("miner", json![miners]), ("modules", json![["TextPrompting"]])],
         }]);
     }
 
@@ -796,17 +832,16 @@ fn test_reveal_by_validator_when_miner_is_duplicated() {
     let mut answer: Vec<AccountId> = generate_validator_answer();
     answer[9] = "miner1.near".parse().unwrap();
     let message = "It's a cool NFT".to_string();
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message);
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message, None);
 
-    assert_eq!(result, RevealValidatorResult::Fail);
+    assert_eq!(result, RevealValidatorResult::Fail(RevealError::RepeatedAccount));
 
     assert_logs(vec![Log::Message("Repeated account: miner1.near".to_string())]);
 }
 
 #[test]
-#[should_panic]
 fn test_reveal_by_validator_when_dont_have_a_commit_answer() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -815,8 +850,8 @@ fn test_reveal_by_validator_when_dont_have_a_commit_answer() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -824,7 +859,11 @@ fn test_reveal_by_validator_when_dont_have_a_commit_answer() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -845,13 +884,13 @@ fn test_reveal_by_validator_when_dont_have_a_commit_answer() {
 
     for (index, miners) in registered_miners.clone().into_iter().enumerate() {
         Environment::with_account(miners.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
-        contract.register_miner();
+        contract.register_miner(vec![Module::TextPrompting]);
         contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), default_answer_miners[index].clone());
 
         assert_logs(vec![
             Log::Event {
                 event_name: "register_miner".to_string(),
-                data: vec![("miner", json![miners])],
+                data: vec![("miner", json![miners]), ("modules", json![["TextPrompting"]])],
             },
             Log::Event {
                 event_name: "commit_miner".to_string(),
@@ -867,13 +906,13 @@ fn test_reveal_by_validator_when_dont_have_a_commit_answer() {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
         let answer = true;
         let message = "It's a cool NFT";
-        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.to_string(), None);
         assert_eq!(result, RevealMinerResult::Success);
         assert_logs(vec![Log::Event {
             event_name: "reveal_miner".to_string(),
             data: vec![
                 ("request_id", json![DEFAULT_REQUEST_ID]),
-                ("answer", json![answer]),
+                ("answer", json![{ "YesNo": answer }]),
                 ("message", json![message]),
             ],
         }]);
@@ -883,5 +922,8 @@ fn test_reveal_by_validator_when_dont_have_a_commit_answer() {
 
     let answer = generate_validator_answer();
     let message = "It's a cool NFT".to_string();
-    contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer, message);
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer, message, None);
+
+    assert_eq!(result, RevealValidatorResult::Fail(RevealError::ProposalNotFound));
+    assert_logs(vec![Log::Message("proposal not found".to_string())]);
 }
@@ -0,0 +1,134 @@
+use near_sdk::{test_utils::get_logs, NearToken};
+use serde_json::json;
+
+use common::constants::{
+    DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER, MINER_1, REVEAL_TOPTEN_TIME, VALIDATOR_1, VALIDATOR_2,
+    VALIDATOR_2_ANSWER, VALIDATOR_3, VALIDATOR_3_ANSWER,
+};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{
+    assert_logs, commit_by_validators, default_culture, get_account_for_validator, get_default_miner_account, get_default_protocol_account, get_owner_account,
+    reveal_by_validators, setup_request_with_revealed_miners,
+};
+
+use earthmind_rs::{ClaimRewardsResult, Config, Contract, Module};
+
+pub mod common;
+
+#[test]
+fn test_rewards_are_split_between_top_miners_and_honest_validators() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_revealed_miners(&mut contract);
+
+    commit_by_validators(
+        &mut contract,
+        &[
+            (VALIDATOR_1, DEFAULT_VALIDATOR_ANSWER),
+            (VALIDATOR_2, VALIDATOR_2_ANSWER),
+            (VALIDATOR_3, VALIDATOR_3_ANSWER),
+        ],
+    );
+
+    // @dev validator3 never reveals so it doesn't get a share of the pool
+    reveal_by_validators(&mut contract, &[VALIDATOR_1, VALIDATOR_2]);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.get_top_voters(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(contract.get_rewards(get_default_miner_account()), NearToken::from_millinear(350));
+    assert_eq!(contract.get_rewards(get_account_for_validator(VALIDATOR_1)), NearToken::from_millinear(750));
+    assert_eq!(contract.get_rewards(get_account_for_validator(VALIDATOR_2)), NearToken::from_millinear(750));
+    assert_eq!(contract.get_rewards(get_account_for_validator(VALIDATOR_3)), NearToken::from_near(0));
+}
+
+#[test]
+fn test_rewards_are_distributed_only_once() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_revealed_miners(&mut contract);
+
+    commit_by_validators(&mut contract, &[(VALIDATOR_1, DEFAULT_VALIDATOR_ANSWER)]);
+    reveal_by_validators(&mut contract, &[VALIDATOR_1]);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.get_top_voters(DEFAULT_REQUEST_ID.to_string());
+    contract.get_top_voters(DEFAULT_REQUEST_ID.to_string());
+
+    let logs = get_logs();
+    assert_eq!(logs.len(), 3);
+    assert!(logs[1].contains(r#""event":"reward_distributed""#));
+    assert!(logs[2].contains(r#""event":"top_miners""#));
+
+    assert_eq!(contract.get_rewards(get_default_miner_account()), NearToken::from_millinear(350));
+    assert_eq!(contract.get_rewards(get_account_for_validator(VALIDATOR_1)), NearToken::from_millinear(1500));
+}
+
+#[test]
+fn test_rewards_go_back_to_the_requester_when_nobody_voted() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let protocol = get_default_protocol_account();
+    Environment::with_account(protocol.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    Environment::with_account(protocol.clone()).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
+    contract.get_top_voters(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(contract.get_rewards(protocol), DEFAULT_DEPOSIT_PROTOCOL);
+}
+
+#[test]
+fn test_claim_rewards() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_revealed_miners(&mut contract);
+
+    commit_by_validators(&mut contract, &[(VALIDATOR_1, DEFAULT_VALIDATOR_ANSWER)]);
+    reveal_by_validators(&mut contract, &[VALIDATOR_1]);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.get_top_voters(DEFAULT_REQUEST_ID.to_string());
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
+
+    let result = contract.claim_rewards();
+
+    assert_eq!(result, ClaimRewardsResult::Success);
+    assert_eq!(contract.get_rewards(miner), NearToken::from_near(0));
+    assert_logs(vec![Log::Event {
+        event_name: "claim_rewards".to_string(),
+        data: vec![("account", json![MINER_1]), ("amount", json![NearToken::from_millinear(350)])],
+    }]);
+
+    let result = contract.claim_rewards();
+
+    assert_eq!(result, ClaimRewardsResult::NothingToClaim);
+}
+
+#[test]
+#[should_panic(expected = "Deposit is less than the required to request a governance decision")]
+fn test_request_governance_decision_when_fee_is_less_than_minimum() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let protocol = get_default_protocol_account();
+    Environment::with_account(protocol.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, None);
+
+    Environment::with_account(protocol).with_attached_deposit(NearToken::from_millinear(1)).create();
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+}
@@ -0,0 +1,138 @@
+use near_sdk::AccountId;
+
+use common::constants::{DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME};
+use common::environment::Environment;
+use common::types::RequestParams;
+use common::utils::{get_account_for_miner, get_default_protocol_account, get_owner_account, register_request, setup_request_with_small_ranking};
+
+use earthmind_rs::{Config, Contract, RevealValidatorResult, ScoringMode, Votes, MAX_POSITION_WEIGHT};
+
+pub mod common;
+
+const MESSAGE: &str = "It's a cool NFT";
+
+// @dev miner3 first, miner1 second and miner2 last
+fn ranking() -> Vec<AccountId> {
+    ["miner3.near", "miner1.near", "miner2.near"].into_iter().map(get_account_for_miner).collect()
+}
+
+fn reveal_and_rank(contract: &mut Contract, scoring: Option<ScoringMode>) -> Vec<(AccountId, Votes)> {
+    setup_request_with_small_ranking(contract, scoring, &ranking());
+
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), ranking(), MESSAGE.to_string(), None);
+    assert_eq!(result, RevealValidatorResult::Success);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.get_top_voters(DEFAULT_REQUEST_ID.to_string())
+}
+
+#[test]
+fn test_approval_scoring_ties_are_ranked_by_account() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let top_miners = reveal_and_rank(&mut contract, None);
+
+    assert_eq!(
+        top_miners,
+        vec![
+            (get_account_for_miner("miner1.near"), 1),
+            (get_account_for_miner("miner2.near"), 1),
+            (get_account_for_miner("miner3.near"), 1),
+        ]
+    );
+}
+
+#[test]
+fn test_borda_scoring() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let top_miners = reveal_and_rank(&mut contract, Some(ScoringMode::Borda));
+
+    assert_eq!(
+        top_miners,
+        vec![
+            (get_account_for_miner("miner3.near"), 3),
+            (get_account_for_miner("miner1.near"), 2),
+            (get_account_for_miner("miner2.near"), 1),
+        ]
+    );
+    assert_eq!(
+        contract.get_votes_for_miner(DEFAULT_REQUEST_ID.to_string(), get_account_for_miner("miner3.near")),
+        Some(3)
+    );
+}
+
+#[test]
+fn test_weighted_scoring() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let top_miners = reveal_and_rank(&mut contract, Some(ScoringMode::Weighted { weights: vec![5, 0, 2] }));
+
+    assert_eq!(
+        top_miners,
+        vec![
+            (get_account_for_miner("miner3.near"), 5),
+            (get_account_for_miner("miner2.near"), 2),
+            (get_account_for_miner("miner1.near"), 0),
+        ]
+    );
+}
+
+#[test]
+fn test_scoring_mode_is_stored_on_the_request() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_request(
+        &mut contract,
+        RequestParams {
+            ranking_size: Some(3),
+            scoring: Some(ScoringMode::Borda),
+            ..RequestParams::default()
+        },
+    );
+
+    let summary = contract.get_request_summary(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(summary.scoring, ScoringMode::Borda);
+}
+
+#[test]
+#[should_panic(expected = "Invalid scoring mode")]
+fn test_weighted_scoring_when_weights_dont_match_the_ranking_size() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    register_request(
+        &mut contract,
+        RequestParams {
+            ranking_size: Some(3),
+            scoring: Some(ScoringMode::Weighted { weights: vec![3, 2] }),
+            ..RequestParams::default()
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid scoring mode")]
+fn test_weighted_scoring_when_a_weight_is_too_high() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    register_request(
+        &mut contract,
+        RequestParams {
+            ranking_size: Some(3),
+            scoring: Some(ScoringMode::Weighted {
+                weights: vec![MAX_POSITION_WEIGHT + 1, 2, 1],
+            }),
+            ..RequestParams::default()
+        },
+    );
+}
+
+#[test]
+fn test_get_top_10_voters_is_an_alias_of_get_top_voters() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    let top_miners = reveal_and_rank(&mut contract, None);
+
+    assert_eq!(contract.get_top_10_voters(DEFAULT_REQUEST_ID.to_string()), top_miners);
+}
@@ -0,0 +1,250 @@
+use near_sdk::NearToken;
+use serde_json::json;
+
+use common::constants::{
+    COMMIT_VALIDATOR_TIME, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER,
+    DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER, MINER_2, REVEAL_MINER_TIME, REVEAL_TOPTEN_TIME, REVEAL_VALIDATOR_TIME, VALIDATOR_1,
+};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{
+    assert_logs, default_culture, default_miners_commit_answer, get_account_for_miner, get_default_miner_account, get_default_protocol_account,
+    get_default_validator_account, get_owner_account,
+};
+
+use earthmind_rs::{Answer, Config, Contract, Module, Participant, RevealMinerResult, SettleRequestResult};
+
+pub mod common;
+
+fn setup_request_with_unrevealed_commits(contract: &mut Contract) {
+    let protocol = get_default_protocol_account();
+    Environment::with_account(protocol).with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL).create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    // @dev miner1 commits and reveals, miner2 commits and disappears
+    let miner_1 = get_default_miner_account();
+    Environment::with_account(miner_1.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+
+    let miner_2 = get_account_for_miner(MINER_2);
+    Environment::with_account(miner_2).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), default_miners_commit_answer()[1].clone());
+
+    Environment::with_account(miner_1).with_block_timestamp(REVEAL_MINER_TIME).create();
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(true), "It's a cool NFT".to_string(), None);
+    assert_eq!(result, RevealMinerResult::Success);
+
+    // @dev validator1 commits and never reveals
+    let validator = get_default_validator_account();
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+
+    Environment::with_account(validator).with_block_timestamp(COMMIT_VALIDATOR_TIME).create();
+    contract.commit_by_validator(DEFAULT_REQUEST_ID.to_string(), DEFAULT_VALIDATOR_ANSWER.to_string());
+}
+
+#[test]
+fn test_settle_request_slashes_unrevealed_commits() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_unrevealed_commits(&mut contract);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    let result = contract.settle_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleRequestResult::Success);
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "slash".to_string(),
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("account", json![MINER_2]),
+                ("participant", json!["Miner"]),
+                ("amount", json![NearToken::from_millinear(100)]),
+            ],
+        },
+        Log::Event {
+            event_name: "slash".to_string(),
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("account", json![VALIDATOR_1]),
+                ("participant", json!["Validator"]),
+                ("amount", json![NearToken::from_near(1)]),
+            ],
+        },
+    ]);
+
+    assert_eq!(contract.get_stake(Participant::Miner, get_default_miner_account()), Some(DEFAULT_DEPOSIT_MINER));
+    assert_eq!(
+        contract.get_stake(Participant::Miner, get_account_for_miner(MINER_2)),
+        Some(NearToken::from_millinear(900))
+    );
+    assert_eq!(
+        contract.get_stake(Participant::Validator, get_default_validator_account()),
+        Some(NearToken::from_near(9))
+    );
+}
+
+#[test]
+fn test_settle_request_slashes_the_configured_share() {
+    let mut contract = Contract::new(
+        get_owner_account(),
+        Config {
+            slash_basis_points: 5_000,
+            ..Config::default()
+        },
+    );
+    setup_request_with_unrevealed_commits(&mut contract);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    let result = contract.settle_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleRequestResult::Success);
+    assert_eq!(
+        contract.get_stake(Participant::Miner, get_account_for_miner(MINER_2)),
+        Some(NearToken::from_millinear(500))
+    );
+    assert_eq!(
+        contract.get_stake(Participant::Validator, get_default_validator_account()),
+        Some(NearToken::from_near(5))
+    );
+}
+
+#[test]
+fn test_settle_request_when_request_not_ended() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_unrevealed_commits(&mut contract);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_VALIDATOR_TIME)
+        .create();
+
+    let result = contract.settle_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleRequestResult::NotEnded);
+    assert_logs(vec![Log::Message(format!("Request has not ended: {}", DEFAULT_REQUEST_ID))]);
+}
+
+#[test]
+fn test_settle_request_when_request_not_registered() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account()).create();
+
+    let result = contract.settle_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleRequestResult::RequestNotFound);
+    assert_logs(vec![Log::Message(format!("Request is not registered: {}", DEFAULT_REQUEST_ID))]);
+}
+
+#[test]
+fn test_settle_request_when_already_settled() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_unrevealed_commits(&mut contract);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    contract.settle_request(DEFAULT_REQUEST_ID.to_string());
+    let result = contract.settle_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleRequestResult::AlreadySettled);
+    assert_eq!(
+        contract.get_stake(Participant::Miner, get_account_for_miner(MINER_2)),
+        Some(NearToken::from_millinear(900))
+    );
+}
+
+#[test]
+fn test_settle_request_after_a_pause_during_the_miners_reveal() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(default_culture(), vec![Module::TextPrompting], None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    let miner = get_account_for_miner(MINER_2);
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), default_miners_commit_answer()[1].clone());
+
+    // @dev The miner can't reveal while the contract is paused
+    Environment::with_account(get_owner_account()).with_block_timestamp(REVEAL_MINER_TIME).create();
+    contract.pause();
+
+    Environment::with_account(get_owner_account())
+        .with_block_timestamp(COMMIT_VALIDATOR_TIME)
+        .create();
+    contract.unpause();
+
+    Environment::with_account(get_default_validator_account())
+        .with_block_timestamp(COMMIT_VALIDATOR_TIME)
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+    contract.commit_by_validator(DEFAULT_REQUEST_ID.to_string(), DEFAULT_VALIDATOR_ANSWER.to_string());
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    let result = contract.settle_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleRequestResult::Success);
+
+    // @dev Only the validator had its whole reveal window
+    assert_logs(vec![Log::Event {
+        event_name: "slash".to_string(),
+        data: vec![
+            ("request_id", json![DEFAULT_REQUEST_ID]),
+            ("account", json![VALIDATOR_1]),
+            ("participant", json!["Validator"]),
+            ("amount", json![NearToken::from_near(1)]),
+        ],
+    }]);
+
+    assert_eq!(contract.get_stake(Participant::Miner, miner), Some(DEFAULT_DEPOSIT_MINER));
+}
+
+#[test]
+fn test_settle_request_while_paused_during_the_validators_reveal() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_unrevealed_commits(&mut contract);
+
+    Environment::with_account(get_owner_account())
+        .with_block_timestamp(REVEAL_VALIDATOR_TIME)
+        .create();
+    contract.pause();
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    let result = contract.settle_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleRequestResult::Success);
+    assert_eq!(
+        contract.get_stake(Participant::Miner, get_account_for_miner(MINER_2)),
+        Some(NearToken::from_millinear(900))
+    );
+    assert_eq!(
+        contract.get_stake(Participant::Validator, get_default_validator_account()),
+        Some(DEFAULT_DEPOSIT_VALIDATOR)
+    );
+}
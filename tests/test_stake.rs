@@ -0,0 +1,308 @@
+use near_sdk::NearToken;
+use serde_json::json;
+
+use common::constants::{
+    DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID,
+    DEFAULT_TIMESTAMP, MINER_1, REVEAL_TOPTEN_TIME, UNBONDING_TIME, VALIDATOR_1,
+};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{
+    assert_logs, default_culture, get_default_miner_account, get_default_protocol_account, get_default_validator_account, get_owner_account, get_transfers,
+};
+
+use earthmind_rs::{
+    AddStakeResult, CommitMinerResult, Config, Contract, DecreaseStakeResult, Module, Participant, PhaseDurations, StakeDecrease, WithdrawStakeResult,
+};
+
+pub mod common;
+
+fn register_miner(contract: &mut Contract, stake: NearToken) {
+    Environment::with_account(get_default_miner_account()).with_attached_deposit(stake).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+}
+
+#[test]
+fn test_add_stake_by_miner() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_miner(&mut contract, DEFAULT_DEPOSIT_MINER);
+
+    Environment::with_account(get_default_miner_account())
+        .with_attached_deposit(NearToken::from_near(2))
+        .create();
+    let result = contract.add_stake(Participant::Miner);
+
+    assert_eq!(result, AddStakeResult::Success);
+    assert_eq!(
+        contract.get_stake(Participant::Miner, get_default_miner_account()),
+        Some(NearToken::from_near(3))
+    );
+    assert_logs(vec![Log::Event {
+        event_name: "add_stake".to_string(),
+        data: vec![
+            ("account", json![MINER_1]),
+            ("participant", json!["Miner"]),
+            ("amount", json![NearToken::from_near(2)]),
+            ("stake", json![NearToken::from_near(3)]),
+        ],
+    }]);
+}
+
+#[test]
+fn test_add_stake_when_not_registered_refunds_the_deposit() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_validator_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    let result = contract.add_stake(Participant::Validator);
+
+    assert_eq!(result, AddStakeResult::NotRegistered);
+    assert_eq!(get_transfers(), vec![(get_default_validator_account(), DEFAULT_DEPOSIT_VALIDATOR)]);
+    assert_logs(vec![Log::Message("Account not registered: validator1.near".to_string())]);
+}
+
+#[test]
+fn test_add_stake_when_unbonding_refunds_the_deposit() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_miner(&mut contract, DEFAULT_DEPOSIT_MINER);
+    contract.request_unstake(Participant::Miner);
+
+    let result = contract.add_stake(Participant::Miner);
+
+    assert_eq!(result, AddStakeResult::Unbonding);
+    assert_eq!(get_transfers(), vec![(get_default_miner_account(), DEFAULT_DEPOSIT_MINER)]);
+    assert_eq!(contract.get_stake(Participant::Miner, get_default_miner_account()), Some(DEFAULT_DEPOSIT_MINER));
+}
+
+#[test]
+#[should_panic(expected = "Stake amount must be greater than zero")]
+fn test_add_stake_without_deposit() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_miner(&mut contract, DEFAULT_DEPOSIT_MINER);
+
+    Environment::with_account(get_default_miner_account())
+        .with_attached_deposit(NearToken::from_near(0))
+        .create();
+    contract.add_stake(Participant::Miner);
+}
+
+#[test]
+fn test_decrease_stake_and_withdraw_stake_decreases_by_validator() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let validator = get_default_validator_account();
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(NearToken::from_near(15))
+        .create();
+    contract.register_validator();
+
+    let result = contract.decrease_stake(Participant::Validator, NearToken::from_near(5));
+
+    assert_eq!(result, DecreaseStakeResult::Success);
+    assert_eq!(contract.get_stake(Participant::Validator, validator.clone()), Some(DEFAULT_DEPOSIT_VALIDATOR));
+    assert_eq!(
+        contract.get_stake_decreases(Participant::Validator, validator.clone()),
+        vec![StakeDecrease {
+            amount: NearToken::from_near(5),
+            available_at: UNBONDING_TIME,
+        }]
+    );
+    assert_logs(vec![
+        Log::Event {
+            event_name: "register_validator".to_string(),
+            data: vec![("validator", json![VALIDATOR_1])],
+        },
+        Log::Event {
+            event_name: "decrease_stake".to_string(),
+            data: vec![
+                ("account", json![VALIDATOR_1]),
+                ("participant", json!["Validator"]),
+                ("amount", json![NearToken::from_near(5)]),
+                ("stake", json![DEFAULT_DEPOSIT_VALIDATOR]),
+                ("available_at", json![UNBONDING_TIME]),
+            ],
+        },
+    ]);
+
+    Environment::with_account(validator.clone()).with_block_timestamp(UNBONDING_TIME).create();
+    let result = contract.withdraw_stake_decreases(Participant::Validator);
+
+    assert_eq!(result, WithdrawStakeResult::Success);
+    assert_eq!(get_transfers(), vec![(validator.clone(), NearToken::from_near(5))]);
+    assert!(contract.get_stake_decreases(Participant::Validator, validator.clone()).is_empty());
+    assert!(contract.is_validator_registered(validator));
+    assert_logs(vec![Log::Event {
+        event_name: "withdraw_stake".to_string(),
+        data: vec![
+            ("account", json![VALIDATOR_1]),
+            ("participant", json!["Validator"]),
+            ("amount", json![NearToken::from_near(5)]),
+        ],
+    }]);
+}
+
+#[test]
+fn test_decrease_stake_below_the_minimum() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_miner(&mut contract, NearToken::from_near(3));
+
+    let result = contract.decrease_stake(Participant::Miner, NearToken::from_millinear(2500));
+
+    assert_eq!(result, DecreaseStakeResult::BelowMinimum);
+    assert_eq!(
+        contract.get_stake(Participant::Miner, get_default_miner_account()),
+        Some(NearToken::from_near(3))
+    );
+    assert!(contract.get_stake_decreases(Participant::Miner, get_default_miner_account()).is_empty());
+}
+
+#[test]
+fn test_decrease_stake_checks_the_current_minimum() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_miner(&mut contract, NearToken::from_near(3));
+
+    Environment::with_account(get_owner_account()).create();
+    contract.update_config(Config {
+        min_miner_stake: NearToken::from_near(2),
+        ..Config::default()
+    });
+
+    Environment::with_account(get_default_miner_account()).create();
+    let result = contract.decrease_stake(Participant::Miner, NearToken::from_near(2));
+
+    assert_eq!(result, DecreaseStakeResult::BelowMinimum);
+    assert_logs(vec![Log::Message("Stake would be below the minimum: 2.00 NEAR".to_string())]);
+}
+
+#[test]
+fn test_decrease_stake_when_unbonding() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_miner(&mut contract, NearToken::from_near(3));
+    contract.request_unstake(Participant::Miner);
+
+    let result = contract.decrease_stake(Participant::Miner, NearToken::from_near(1));
+
+    assert_eq!(result, DecreaseStakeResult::Unbonding);
+}
+
+#[test]
+fn test_decrease_stake_with_an_unrevealed_commit() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(default_culture(), vec![Module::TextPrompting], None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    register_miner(&mut contract, NearToken::from_near(3));
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+
+    // @dev The commit is never revealed, the stake can't leave before it is slashed
+    Environment::with_account(get_default_miner_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    let result = contract.decrease_stake(Participant::Miner, NearToken::from_near(1));
+
+    assert_eq!(result, DecreaseStakeResult::OpenCommits);
+    assert_logs(vec![Log::Message(format!("Account has open commits: {}", MINER_1))]);
+    assert!(contract.get_stake_decreases(Participant::Miner, get_default_miner_account()).is_empty());
+
+    contract.settle_request(DEFAULT_REQUEST_ID.to_string());
+    assert_eq!(
+        contract.get_stake(Participant::Miner, get_default_miner_account()),
+        Some(NearToken::from_millinear(2700))
+    );
+
+    let result = contract.decrease_stake(Participant::Miner, NearToken::from_near(1));
+
+    assert_eq!(result, DecreaseStakeResult::Success);
+}
+
+#[test]
+fn test_decrease_stake_when_not_registered() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    Environment::with_account(get_default_miner_account()).create();
+
+    let result = contract.decrease_stake(Participant::Miner, NearToken::from_near(1));
+
+    assert_eq!(result, DecreaseStakeResult::NotRegistered);
+}
+
+#[test]
+fn test_withdraw_stake_decreases_only_releases_the_ended_decreases() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_miner(&mut contract, NearToken::from_near(5));
+
+    let miner = get_default_miner_account();
+    contract.decrease_stake(Participant::Miner, NearToken::from_near(1));
+
+    Environment::with_account(miner.clone()).with_block_timestamp(DEFAULT_TIMESTAMP + 1).create();
+    contract.decrease_stake(Participant::Miner, NearToken::from_near(2));
+
+    Environment::with_account(miner.clone()).create();
+    let result = contract.withdraw_stake_decreases(Participant::Miner);
+
+    assert_eq!(result, WithdrawStakeResult::StillUnbonding);
+    assert_logs(vec![Log::Message(format!("Stake is unbonding until: {}", UNBONDING_TIME))]);
+
+    Environment::with_account(miner.clone()).with_block_timestamp(UNBONDING_TIME).create();
+    let result = contract.withdraw_stake_decreases(Participant::Miner);
+
+    assert_eq!(result, WithdrawStakeResult::Success);
+    assert_eq!(get_transfers(), vec![(miner.clone(), NearToken::from_near(1))]);
+    assert_eq!(
+        contract.get_stake_decreases(Participant::Miner, miner.clone()),
+        vec![StakeDecrease {
+            amount: NearToken::from_near(2),
+            available_at: UNBONDING_TIME + 1,
+        }]
+    );
+    assert_eq!(contract.get_stake(Participant::Miner, miner), Some(NearToken::from_near(2)));
+}
+
+#[test]
+fn test_withdraw_stake_decreases_with_an_open_commit() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    // @dev Every phase lasts longer than the unbonding period
+    let three_days = 3 * 24 * 60 * 60 * 1_000_000_000;
+    let timing = PhaseDurations {
+        commit_miner: three_days,
+        reveal_miner: three_days,
+        commit_validator: three_days,
+        reveal_validator: three_days,
+    };
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(default_culture(), vec![Module::TextPrompting], Some(timing));
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    let miner = get_default_miner_account();
+    register_miner(&mut contract, NearToken::from_near(5));
+    contract.decrease_stake(Participant::Miner, NearToken::from_near(1));
+    let result = contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+    assert_eq!(result, CommitMinerResult::Success);
+
+    // @dev The decreased amount already left the stake that the open commit can lose
+    Environment::with_account(miner.clone()).with_block_timestamp(UNBONDING_TIME).create();
+    let result = contract.withdraw_stake_decreases(Participant::Miner);
+
+    assert_eq!(result, WithdrawStakeResult::Success);
+    assert_eq!(get_transfers(), vec![(miner.clone(), NearToken::from_near(1))]);
+    assert_eq!(contract.get_stake(Participant::Miner, miner), Some(NearToken::from_near(4)));
+}
+
+#[test]
+fn test_withdraw_stake_decreases_when_not_requested() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_miner(&mut contract, DEFAULT_DEPOSIT_MINER);
+
+    let result = contract.withdraw_stake_decreases(Participant::Miner);
+
+    assert_eq!(result, WithdrawStakeResult::NotRequested);
+    assert!(get_transfers().is_empty());
+}
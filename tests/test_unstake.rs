@@ -0,0 +1,273 @@
+use near_sdk::test_utils::get_logs;
+use serde_json::json;
+
+use common::constants::{
+    DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID,
+    DEFAULT_TIMESTAMP, MINER_1, UNBONDING_TIME, VALIDATOR_1,
+};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{assert_logs, default_culture, get_default_miner_account, get_default_protocol_account, get_default_validator_account, get_owner_account};
+
+use earthmind_rs::{CommitError, CommitMinerResult, Config, Contract, Module, Participant, PhaseDurations, RequestUnstakeResult, WithdrawStakeResult};
+
+pub mod common;
+
+#[test]
+fn test_request_unstake_and_withdraw_stake_by_miner() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+
+    let result = contract.request_unstake(Participant::Miner);
+
+    assert_eq!(result, RequestUnstakeResult::Success);
+    assert!(contract.is_unbonding(Participant::Miner, miner.clone()));
+    assert!(contract.is_miner_registered(miner.clone()));
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "register_miner".to_string(),
+            data: vec![("miner", json![MINER_1]), ("modules", json![["TextPrompting"]])],
+        },
+        Log::Event {
+            event_name: "request_unstake".to_string(),
+            data: vec![
+                ("account", json![MINER_1]),
+                ("participant", json!["Miner"]),
+                ("amount", json![DEFAULT_DEPOSIT_MINER]),
+                ("available_at", json![UNBONDING_TIME]),
+            ],
+        },
+    ]);
+
+    Environment::with_account(miner.clone()).with_block_timestamp(UNBONDING_TIME).create();
+
+    let result = contract.withdraw_stake(Participant::Miner);
+
+    assert_eq!(result, WithdrawStakeResult::Success);
+    assert!(!contract.is_unbonding(Participant::Miner, miner.clone()));
+    assert!(!contract.is_miner_registered(miner));
+
+    assert_logs(vec![Log::Event {
+        event_name: "withdraw_stake".to_string(),
+        data: vec![
+            ("account", json![MINER_1]),
+            ("participant", json!["Miner"]),
+            ("amount", json![DEFAULT_DEPOSIT_MINER]),
+        ],
+    }]);
+}
+
+#[test]
+fn test_request_unstake_and_withdraw_stake_by_validator() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let validator = get_default_validator_account();
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+
+    let result = contract.request_unstake(Participant::Validator);
+    assert_eq!(result, RequestUnstakeResult::Success);
+
+    Environment::with_account(validator.clone()).with_block_timestamp(UNBONDING_TIME).create();
+
+    let result = contract.withdraw_stake(Participant::Validator);
+
+    assert_eq!(result, WithdrawStakeResult::Success);
+    assert!(!contract.is_validator_registered(validator));
+
+    assert_logs(vec![Log::Event {
+        event_name: "withdraw_stake".to_string(),
+        data: vec![
+            ("account", json![VALIDATOR_1]),
+            ("participant", json!["Validator"]),
+            ("amount", json![DEFAULT_DEPOSIT_VALIDATOR]),
+        ],
+    }]);
+}
+
+#[test]
+fn test_request_unstake_when_not_registered() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner).create();
+
+    let result = contract.request_unstake(Participant::Miner);
+
+    assert_eq!(result, RequestUnstakeResult::NotRegistered);
+    assert_logs(vec![Log::Message("Account not registered: miner1.near".to_string())]);
+}
+
+#[test]
+fn test_request_unstake_when_already_requested() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    contract.request_unstake(Participant::Miner);
+
+    let result = contract.request_unstake(Participant::Miner);
+
+    assert_eq!(result, RequestUnstakeResult::AlreadyRequested);
+    assert_eq!(get_logs().last().unwrap(), "Unstake already requested: miner1.near");
+}
+
+#[test]
+fn test_withdraw_stake_when_not_requested() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+
+    let result = contract.withdraw_stake(Participant::Miner);
+
+    assert_eq!(result, WithdrawStakeResult::NotRequested);
+    assert!(contract.is_miner_registered(miner));
+}
+
+#[test]
+fn test_withdraw_stake_when_still_unbonding() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    contract.request_unstake(Participant::Miner);
+
+    Environment::with_account(miner.clone()).with_block_timestamp(UNBONDING_TIME - 1).create();
+
+    let result = contract.withdraw_stake(Participant::Miner);
+
+    assert_eq!(result, WithdrawStakeResult::StillUnbonding);
+    assert!(contract.is_miner_registered(miner));
+}
+
+#[test]
+fn test_withdraw_stake_after_the_configured_unbonding_period() {
+    let unbonding_period = 60 * 60 * 1_000_000_000;
+    let mut contract = Contract::new(
+        get_owner_account(),
+        Config {
+            unbonding_period,
+            ..Config::default()
+        },
+    );
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    contract.request_unstake(Participant::Miner);
+
+    Environment::with_account(miner.clone())
+        .with_block_timestamp(DEFAULT_TIMESTAMP + unbonding_period - 1)
+        .create();
+    assert_eq!(contract.withdraw_stake(Participant::Miner), WithdrawStakeResult::StillUnbonding);
+
+    Environment::with_account(miner.clone())
+        .with_block_timestamp(DEFAULT_TIMESTAMP + unbonding_period)
+        .create();
+    assert_eq!(contract.withdraw_stake(Participant::Miner), WithdrawStakeResult::Success);
+    assert!(!contract.is_miner_registered(miner));
+}
+
+#[test]
+fn test_withdraw_stake_after_committed_request_ended() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let protocol = get_default_protocol_account();
+    Environment::with_account(protocol).with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL).create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+
+    let result = contract.request_unstake(Participant::Miner);
+    assert_eq!(result, RequestUnstakeResult::Success);
+
+    Environment::with_account(miner.clone()).with_block_timestamp(UNBONDING_TIME).create();
+
+    // @dev The commit was never revealed, so the stake stays locked until the request is settled
+    let result = contract.withdraw_stake(Participant::Miner);
+    assert_eq!(result, WithdrawStakeResult::OpenCommits);
+
+    contract.settle_request(DEFAULT_REQUEST_ID.to_string());
+
+    let result = contract.withdraw_stake(Participant::Miner);
+
+    assert_eq!(result, WithdrawStakeResult::Success);
+    assert!(!contract.is_miner_registered(miner));
+    assert_eq!(
+        get_logs().last().unwrap(),
+        r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"withdraw_stake","data":[{"account":"miner1.near","participant":"Miner","amount":"900000000000000000000000"}]}"#
+    );
+}
+
+#[test]
+fn test_withdraw_stake_when_committed_request_is_still_open() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let protocol = get_default_protocol_account();
+    Environment::with_account(protocol).with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL).create();
+
+    // @dev Every phase lasts longer than the unbonding period
+    let three_days = 3 * 24 * 60 * 60 * 1_000_000_000;
+    let timing = PhaseDurations {
+        commit_miner: three_days,
+        reveal_miner: three_days,
+        commit_validator: three_days,
+        reveal_validator: three_days,
+    };
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, Some(timing));
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+    contract.request_unstake(Participant::Miner);
+
+    Environment::with_account(miner.clone()).with_block_timestamp(UNBONDING_TIME).create();
+
+    let result = contract.withdraw_stake(Participant::Miner);
+
+    assert_eq!(result, WithdrawStakeResult::OpenCommits);
+    assert!(contract.is_miner_registered(miner));
+    assert_eq!(get_logs().last().unwrap(), "Account has open commits: miner1.near");
+}
+
+#[test]
+fn test_commit_by_miner_when_miner_is_unbonding() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    let protocol = get_default_protocol_account();
+    Environment::with_account(protocol).with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL).create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner(vec![Module::TextPrompting]);
+    contract.request_unstake(Participant::Miner);
+
+    let result = contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+
+    assert_eq!(result, CommitMinerResult::Fail(CommitError::Unbonding));
+    assert_eq!(get_logs().last().unwrap(), "Miner is unbonding: miner1.near");
+}
@@ -0,0 +1,140 @@
+use serde_json::json;
+
+use common::constants::{ACCOUNT_1, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_REQUEST_ID};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{assert_logs, default_culture, get_default_protocol_account, get_owner_account, get_request_id, register_protocol};
+
+use earthmind_rs::{Config, Contract, CultureProfile, Module, UpdateProtocolResult, MAX_CULTURE_TAGS};
+
+pub mod common;
+
+const NEW_CULTURE_HASH: &str = "a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90";
+
+fn new_culture() -> CultureProfile {
+    CultureProfile {
+        document_hash: NEW_CULTURE_HASH.to_string(),
+        tags: vec!["governance".to_string(), "defi".to_string()],
+    }
+}
+
+fn request_governance_decision(contract: &mut Contract, message: &str) {
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.request_governance_decision(message.to_string(), None, None, None, None, None);
+}
+
+#[test]
+fn test_register_protocol_stores_the_culture() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+
+    assert_eq!(contract.get_protocol_culture(get_default_protocol_account()), Some(default_culture()));
+    assert_eq!(contract.get_protocol_version(get_default_protocol_account()), Some(1));
+}
+
+#[test]
+fn test_update_protocol() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.update_protocol(new_culture());
+
+    assert_eq!(result, UpdateProtocolResult::Success);
+    assert_eq!(contract.get_protocol_culture(get_default_protocol_account()), Some(new_culture()));
+    assert_eq!(contract.get_protocol_version(get_default_protocol_account()), Some(2));
+
+    assert_logs(vec![Log::Event {
+        event_name: "protocol_updated".to_string(),
+        data: vec![("account", json![ACCOUNT_1]), ("version", json![2]), ("culture", json![new_culture()])],
+    }]);
+}
+
+#[test]
+fn test_update_protocol_when_protocol_is_not_registered() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.update_protocol(new_culture());
+
+    assert_eq!(result, UpdateProtocolResult::NotRegistered);
+    assert_logs(vec![Log::Message(format!("Protocol not registered: {}", ACCOUNT_1))]);
+    assert_eq!(contract.get_protocol_version(get_default_protocol_account()), None);
+}
+
+#[test]
+fn test_requests_keep_the_culture_they_were_created_with() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+    request_governance_decision(&mut contract, DEFAULT_MESSAGE_TO_REQUEST);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    contract.update_protocol(new_culture());
+
+    let message = "Should we list this token?";
+    request_governance_decision(&mut contract, message);
+
+    let in_flight = contract.get_request_summary(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    assert_eq!(in_flight.culture, Some(default_culture()));
+    assert_eq!(in_flight.protocol_version, 1);
+
+    let request_id = get_request_id(&get_default_protocol_account(), message, 1);
+    let summary = contract.get_request_summary(request_id.clone()).unwrap();
+    assert_eq!(summary.culture, Some(new_culture()));
+    assert_eq!(summary.protocol_version, 2);
+
+    assert_logs(vec![Log::Event {
+        event_name: "register_request".to_string(),
+        data: vec![
+            ("request_id", json![request_id]),
+            ("culture", json![new_culture()]),
+            ("protocol_version", json![2]),
+        ],
+    }]);
+}
+
+#[test]
+#[should_panic(expected = "Invalid culture profile")]
+fn test_register_protocol_when_document_hash_is_not_a_hash() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(
+        CultureProfile {
+            document_hash: "Governance decision".to_string(),
+            tags: Vec::new(),
+        },
+        vec![Module::TextPrompting],
+        None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid culture profile")]
+fn test_update_protocol_with_too_many_tags() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    contract.update_protocol(CultureProfile {
+        document_hash: NEW_CULTURE_HASH.to_string(),
+        tags: vec!["governance".to_string(); MAX_CULTURE_TAGS + 1],
+    });
+}
+
+#[test]
+#[should_panic(expected = "Invalid culture profile")]
+fn test_update_protocol_with_an_empty_tag() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    register_protocol(&mut contract, &get_default_protocol_account(), vec![Module::TextPrompting]);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    contract.update_protocol(CultureProfile {
+        document_hash: NEW_CULTURE_HASH.to_string(),
+        tags: vec![String::new()],
+    });
+}
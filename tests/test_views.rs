@@ -0,0 +1,143 @@
+use common::constants::{
+    DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_REQUEST_ID, DEFAULT_TIMESTAMP, DEFAULT_VALIDATOR_ANSWER, REVEAL_TOPTEN_TIME,
+    REVEAL_VALIDATOR_TIME, VALIDATOR_1, VALIDATOR_2, VALIDATOR_2_ANSWER,
+};
+use common::environment::Environment;
+use common::utils::{
+    commit_by_validators, default_culture, generate_validator_answer, get_account_for_validator, get_default_miner_account, get_default_protocol_account,
+    get_owner_account, reveal_by_validators, setup_request_with_revealed_miners,
+};
+
+use earthmind_rs::{Answer, AnswerKind, Config, Contract, Module, PhaseDurations, RequestState, RequestSummary, ScoringMode, VoteWeighting};
+
+pub mod common;
+
+#[test]
+fn test_get_request_summary() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
+
+    let summary = contract.get_request_summary(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(
+        summary,
+        Some(RequestSummary {
+            sender: get_default_protocol_account(),
+            request_id: DEFAULT_REQUEST_ID.to_string(),
+            start_time: DEFAULT_TIMESTAMP,
+            timing: PhaseDurations::default(),
+            answer_kind: AnswerKind::YesNo,
+            ranking_size: 10,
+            scoring: ScoringMode::Approval,
+            vote_weighting: VoteWeighting::Equal,
+            module: Some(Module::TextPrompting),
+            culture: Some(default_culture()),
+            protocol_version: 1,
+            stage: RequestState::CommitMiners,
+            committed_miners: 0,
+            revealed_miners: 0,
+            committed_validators: 0,
+            revealed_validators: 0,
+            reward_pool: DEFAULT_DEPOSIT_PROTOCOL,
+            is_settled: false,
+        })
+    );
+}
+
+#[test]
+fn test_get_request_summary_counts_participants() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_revealed_miners(&mut contract);
+
+    commit_by_validators(&mut contract, &[(VALIDATOR_1, DEFAULT_VALIDATOR_ANSWER), (VALIDATOR_2, VALIDATOR_2_ANSWER)]);
+    reveal_by_validators(&mut contract, &[VALIDATOR_1]);
+
+    let summary = contract.get_request_summary(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(summary.stage, RequestState::RevealValidators);
+    assert_eq!(summary.committed_miners, 10);
+    assert_eq!(summary.revealed_miners, 10);
+    assert_eq!(summary.committed_validators, 2);
+    assert_eq!(summary.revealed_validators, 1);
+}
+
+#[test]
+fn test_get_request_summary_when_request_not_registered() {
+    let contract = Contract::new(get_owner_account(), Config::default());
+
+    assert_eq!(contract.get_request_summary(DEFAULT_REQUEST_ID.to_string()), None);
+}
+
+#[test]
+fn test_get_proposals() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_revealed_miners(&mut contract);
+
+    commit_by_validators(&mut contract, &[(VALIDATOR_1, DEFAULT_VALIDATOR_ANSWER), (VALIDATOR_2, VALIDATOR_2_ANSWER)]);
+    reveal_by_validators(&mut contract, &[VALIDATOR_1]);
+
+    let miner_proposal = contract
+        .get_miner_proposal(DEFAULT_REQUEST_ID.to_string(), get_default_miner_account())
+        .unwrap();
+    assert!(miner_proposal.is_revealed);
+    assert_eq!(miner_proposal.answer, Some(Answer::YesNo(true)));
+
+    let validator_proposal = contract
+        .get_validator_proposal(DEFAULT_REQUEST_ID.to_string(), get_account_for_validator(VALIDATOR_1))
+        .unwrap();
+    assert!(validator_proposal.is_revealed);
+    assert_eq!(validator_proposal.proposal_hash, DEFAULT_VALIDATOR_ANSWER);
+    assert_eq!(validator_proposal.miner_addresses, generate_validator_answer());
+
+    let validator_proposal = contract
+        .get_validator_proposal(DEFAULT_REQUEST_ID.to_string(), get_account_for_validator(VALIDATOR_2))
+        .unwrap();
+    assert!(!validator_proposal.is_revealed);
+    assert!(validator_proposal.miner_addresses.is_empty());
+
+    assert!(contract
+        .get_miner_proposal(DEFAULT_REQUEST_ID.to_string(), get_account_for_validator(VALIDATOR_1))
+        .is_none());
+}
+
+#[test]
+fn test_get_votes_and_top_miners() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_revealed_miners(&mut contract);
+
+    commit_by_validators(&mut contract, &[(VALIDATOR_1, DEFAULT_VALIDATOR_ANSWER), (VALIDATOR_2, VALIDATOR_2_ANSWER)]);
+    reveal_by_validators(&mut contract, &[VALIDATOR_1, VALIDATOR_2]);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_VALIDATOR_TIME)
+        .create();
+
+    let votes = contract.get_votes_for_miners(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    let expected: Vec<_> = generate_validator_answer().into_iter().map(|miner| (miner, 2)).collect();
+
+    assert_eq!(votes, expected);
+    assert_eq!(
+        contract.get_votes_for_miner(DEFAULT_REQUEST_ID.to_string(), get_default_miner_account()),
+        Some(2)
+    );
+    assert_eq!(
+        contract.get_votes_for_miner(DEFAULT_REQUEST_ID.to_string(), get_account_for_validator(VALIDATOR_1)),
+        Some(0)
+    );
+    assert_eq!(contract.get_top_miners(DEFAULT_REQUEST_ID.to_string()), Some(Vec::new()));
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    let top_miners = contract.get_top_voters(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(contract.get_top_miners(DEFAULT_REQUEST_ID.to_string()), Some(top_miners));
+}
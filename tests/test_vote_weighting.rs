@@ -0,0 +1,137 @@
+use near_sdk::{AccountId, NearToken};
+use serde_json::json;
+
+use common::constants::{DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME, REVEAL_VALIDATOR_TIME};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{
+    assert_logs, get_account_for_miner, get_default_protocol_account, get_default_validator_account, get_owner_account, setup_request_with_small_ranking,
+    small_group_of_miners,
+};
+
+use earthmind_rs::{Config, Contract, RevealValidatorResult, ScoringMode, VoteWeighting, Votes};
+
+pub mod common;
+
+const MESSAGE: &str = "It's a cool NFT";
+
+fn contract_with_weighting(vote_weighting: VoteWeighting) -> Contract {
+    Contract::new(
+        get_owner_account(),
+        Config {
+            vote_weighting,
+            ..Config::default()
+        },
+    )
+}
+
+// @dev The default validator stakes 10 NEAR and ranks the small group of miners in order
+fn reveal_and_rank(contract: &mut Contract, scoring: Option<ScoringMode>) -> Vec<(AccountId, Votes)> {
+    setup_request_with_small_ranking(contract, scoring, &small_group_of_miners());
+
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), small_group_of_miners(), MESSAGE.to_string(), None);
+    assert_eq!(result, RevealValidatorResult::Success);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.get_top_voters(DEFAULT_REQUEST_ID.to_string())
+}
+
+fn weighted(votes: [Votes; 3]) -> Vec<(AccountId, Votes)> {
+    small_group_of_miners().into_iter().zip(votes).collect()
+}
+
+#[test]
+fn test_capped_vote_weighting() {
+    let mut contract = contract_with_weighting(VoteWeighting::Capped { cap: NearToken::from_near(5) });
+
+    let top_miners = reveal_and_rank(&mut contract, None);
+
+    assert_eq!(top_miners, weighted([5, 5, 5]));
+    assert_eq!(
+        contract.get_votes_for_miner(DEFAULT_REQUEST_ID.to_string(), get_account_for_miner("miner1.near")),
+        Some(5)
+    );
+}
+
+#[test]
+fn test_square_root_vote_weighting_with_borda_scoring() {
+    let mut contract = contract_with_weighting(VoteWeighting::SquareRoot);
+
+    let top_miners = reveal_and_rank(&mut contract, Some(ScoringMode::Borda));
+
+    assert_eq!(top_miners, weighted([9, 6, 3]));
+}
+
+#[test]
+fn test_top_miners_event_reports_weighted_votes() {
+    let mut contract = contract_with_weighting(VoteWeighting::SquareRoot);
+    setup_request_with_small_ranking(&mut contract, None, &small_group_of_miners());
+    contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), small_group_of_miners(), MESSAGE.to_string(), None);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.get_top_voters(DEFAULT_REQUEST_ID.to_string());
+
+    let rewards = small_group_of_miners()
+        .into_iter()
+        .map(|miner| {
+            vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("account", json![miner]),
+                ("amount", json![NearToken::from_yoctonear(DEFAULT_DEPOSIT_PROTOCOL.as_yoctonear() * 7 / 30)]),
+            ]
+        })
+        .chain(std::iter::once(vec![
+            ("request_id", json![DEFAULT_REQUEST_ID]),
+            ("account", json![get_default_validator_account()]),
+            ("amount", json![NearToken::from_millinear(1500)]),
+        ]))
+        .collect();
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "top_miners".to_string(),
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("ranking_size", json![3]),
+                ("top_miners", json![weighted([3, 3, 3])]),
+            ],
+        },
+        Log::EventBatch {
+            event_name: "reward_distributed".to_string(),
+            data: rewards,
+        },
+    ]);
+}
+
+#[test]
+fn test_requests_keep_the_vote_weighting_they_were_created_with() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
+    setup_request_with_small_ranking(&mut contract, None, &small_group_of_miners());
+
+    Environment::with_account(get_owner_account()).create();
+    contract.update_config(Config {
+        vote_weighting: VoteWeighting::SquareRoot,
+        ..Config::default()
+    });
+
+    Environment::with_account(get_default_validator_account())
+        .with_block_timestamp(REVEAL_VALIDATOR_TIME)
+        .create();
+    contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), small_group_of_miners(), MESSAGE.to_string(), None);
+
+    let summary = contract.get_request_summary(DEFAULT_REQUEST_ID.to_string()).unwrap();
+    assert_eq!(summary.vote_weighting, VoteWeighting::Equal);
+    assert_eq!(contract.get_votes_for_miners(DEFAULT_REQUEST_ID.to_string()), Some(weighted([1, 1, 1])));
+}
+
+#[test]
+#[should_panic(expected = "Config value out of bounds")]
+fn test_capped_vote_weighting_when_cap_is_out_of_bounds() {
+    contract_with_weighting(VoteWeighting::Capped {
+        cap: NearToken::from_yoctonear(0),
+    });
+}
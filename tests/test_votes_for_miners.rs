@@ -1,23 +1,24 @@
+use near_sdk::NearToken;
 use serde_json::json;
 
 use common::constants::{
-    COMMIT_VALIDATOR_TIME, DEFAULT_CULTURE, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MESSAGE_TO_REQUEST,
-    DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER, REVEAL_MINER_TIME, REVEAL_TOPTEN_TIME, REVEAL_VALIDATOR_TIME, VALIDATOR_1, VALIDATOR_2, VALIDATOR_3,
+    COMMIT_VALIDATOR_TIME, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_REQUEST_ID,
+    DEFAULT_VALIDATOR_ANSWER, REVEAL_MINER_TIME, REVEAL_TOPTEN_TIME, REVEAL_VALIDATOR_TIME, VALIDATOR_1, VALIDATOR_2, VALIDATOR_3,
 };
 use common::environment::Environment;
 use common::types::Log;
 use common::utils::{
-    assert_logs, default_miners_commit_answer, generate_validator_answer, get_account_for_validator, get_default_protocol_account,
-    get_default_validator_account, group_registered_miners,
+    assert_logs, default_culture, default_miners_commit_answer, generate_validator_answer, get_account_for_validator, get_default_protocol_account,
+    get_default_validator_account, get_owner_account, group_registered_miners,
 };
 
-use earthmind_rs::{Contract, Module, RevealMinerResult, RevealValidatorResult};
+use earthmind_rs::{Answer, Config, Contract, Module, RevealMinerResult, RevealValidatorResult};
 
 pub mod common;
 
 #[test]
 fn test_votes_for_miner_using_one_validator() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -26,8 +27,8 @@ fn test_votes_for_miner_using_one_validator() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -35,7 +36,11 @@ fn test_votes_for_miner_using_one_validator() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -56,13 +61,13 @@ fn test_votes_for_miner_using_one_validator() {
 
     for (index, miners) in registered_miners.clone().into_iter().enumerate() {
         Environment::with_account(miners.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
-        contract.register_miner();
+        contract.register_miner(vec![Module::TextPrompting]);
         contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), default_answer_miners[index].clone());
 
         assert_logs(vec![
             Log::Event {
                 event_name: "register_miner".to_string(),
-                data: vec![("miner", json![miners])],
+                data: vec![("miner", json![miners]), ("modules", json![["TextPrompting"]])],
             },
             Log::Event {
                 event_name: "commit_miner".to_string(),
@@ -78,13 +83,13 @@ fn test_votes_for_miner_using_one_validator() {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
         let answer = true;
         let message = "It's a cool NFT";
-        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.to_string(), None);
         assert_eq!(result, RevealMinerResult::Success);
         assert_logs(vec![Log::Event {
             event_name: "reveal_miner".to_string(),
             data: vec![
                 ("request_id", json![DEFAULT_REQUEST_ID]),
-                ("answer", json![answer]),
+                ("answer", json![{ "YesNo": answer }]),
                 ("message", json![message]),
             ],
         }]);
@@ -105,7 +110,7 @@ fn test_votes_for_miner_using_one_validator() {
 
     let answer = generate_validator_answer();
     let message = "It's a cool NFT".to_string();
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone());
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone(), None);
 
     assert_eq!(result, RevealValidatorResult::Success);
     assert_logs(vec![Log::Event {
@@ -134,7 +139,7 @@ fn test_votes_for_miner_using_one_validator() {
 
 #[test]
 fn test_vote_for_miners_with_multiple_validators() {
-    let mut contract = Contract::new();
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -143,8 +148,8 @@ fn test_vote_for_miners_with_multiple_validators() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -152,7 +157,11 @@ fn test_vote_for_miners_with_multiple_validators() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -174,13 +183,13 @@ fn test_vote_for_miners_with_multiple_validators() {
 
     for (index, miners) in registered_miners.clone().into_iter().enumerate() {
         Environment::with_account(miners.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
-        contract.register_miner();
+        contract.register_miner(vec![Module::TextPrompting]);
         contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), default_answer_miners[index].clone());
 
         assert_logs(vec![
             Log::Event {
                 event_name: "register_miner".to_string(),
-                data: vec![("miner", json![miners])],
+                data: vec![("miner", json![miners]), ("modules", json![["TextPrompting"]])],
             },
             Log::Event {
                 event_name: "commit_miner".to_string(),
@@ -196,13 +205,13 @@ fn test_vote_for_miners_with_multiple_validators() {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
         let answer = true;
         let message = "It's a cool NFT";
-        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.to_string(), None);
         assert_eq!(result, RevealMinerResult::Success);
         assert_logs(vec![Log::Event {
             event_name: "reveal_miner".to_string(),
             data: vec![
                 ("request_id", json![DEFAULT_REQUEST_ID]),
-                ("answer", json![answer]),
+                ("answer", json![{ "YesNo": answer }]),
                 ("message", json![message]),
             ],
         }]);
@@ -223,7 +232,7 @@ fn test_vote_for_miners_with_multiple_validators() {
 
     let answer = generate_validator_answer();
     let message = "It's a cool NFT".to_string();
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone());
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone(), None);
 
     assert_eq!(result, RevealValidatorResult::Success);
 
@@ -270,7 +279,7 @@ fn test_vote_for_miners_with_multiple_validators() {
 
     let answer = generate_validator_answer();
     let message = "It's a cool NFT".to_string();
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone());
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone(), None);
 
     assert_eq!(result, RevealValidatorResult::Success);
 
@@ -290,8 +299,8 @@ fn test_vote_for_miners_with_multiple_validators() {
 }
 
 #[test]
-fn test_get_top_10_voters() {
-    let mut contract = Contract::new();
+fn test_get_top_voters() {
+    let mut contract = Contract::new(get_owner_account(), Config::default());
 
     // @dev Protocol register to earthmind protocol and request a governance decision
     let protocol = get_default_protocol_account();
@@ -300,8 +309,8 @@ fn test_get_top_10_voters() {
         .create();
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
-    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.register_protocol(default_culture(), modules, None);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None, None, None, None, None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -309,7 +318,11 @@ fn test_get_top_10_voters() {
         },
         Log::Event {
             event_name: "register_request".to_string(),
-            data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("culture", json![default_culture()]),
+                ("protocol_version", json![1]),
+            ],
         },
     ]);
 
@@ -353,13 +366,13 @@ fn test_get_top_10_voters() {
 
     for (index, miners) in registered_miners.clone().into_iter().enumerate() {
         Environment::with_account(miners.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
-        contract.register_miner();
+        contract.register_miner(vec![Module::TextPrompting]);
         contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), default_answer_miners[index].clone());
 
         assert_logs(vec![
             Log::Event {
                 event_name: "register_miner".to_string(),
-                data: vec![("miner", json![miners])],
+                data: vec![("miner", json![miners]), ("modules", json![["TextPrompting"]])],
             },
             Log::Event {
                 event_name: "commit_miner".to_string(),
@@ -375,13 +388,13 @@ fn test_get_top_10_voters() {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
         let answer = true;
         let message = "It's a cool NFT";
-        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), Answer::YesNo(answer), message.to_string(), None);
         assert_eq!(result, RevealMinerResult::Success);
         assert_logs(vec![Log::Event {
             event_name: "reveal_miner".to_string(),
             data: vec![
                 ("request_id", json![DEFAULT_REQUEST_ID]),
-                ("answer", json![answer]),
+                ("answer", json![{ "YesNo": answer }]),
                 ("message", json![message]),
             ],
         }]);
@@ -430,7 +443,7 @@ fn test_get_top_10_voters() {
 
     let answer = generate_validator_answer();
     let message = "It's a cool NFT".to_string();
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone());
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone(), None);
 
     assert_eq!(result, RevealValidatorResult::Success);
 
@@ -445,7 +458,7 @@ fn test_get_top_10_voters() {
 
     Environment::with_account(validator_2).with_block_timestamp(REVEAL_VALIDATOR_TIME).create();
 
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone());
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone(), None);
 
     assert_eq!(result, RevealValidatorResult::Success);
 
@@ -460,7 +473,7 @@ fn test_get_top_10_voters() {
 
     Environment::with_account(validator_3).with_block_timestamp(REVEAL_VALIDATOR_TIME).create();
 
-    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone());
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), answer.clone(), message.clone(), None);
 
     assert_eq!(result, RevealValidatorResult::Success);
 
@@ -475,10 +488,40 @@ fn test_get_top_10_voters() {
 
     Environment::with_account(validator_1).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
 
-    let top_ten = contract.get_top_10_voters(DEFAULT_REQUEST_ID.to_string());
+    let top_miners = contract.get_top_voters(DEFAULT_REQUEST_ID.to_string());
 
-    assert_logs(vec![Log::Event {
-        event_name: "topten_miners".to_string(),
-        data: vec![("request_id", json![DEFAULT_REQUEST_ID]), ("topten", json![top_ten])],
-    }]);
+    // @dev 70% of the 5 NEAR pool goes to the top miners and 30% to the three validators
+    let mut rewards: Vec<Vec<(&str, serde_json::Value)>> = top_miners
+        .iter()
+        .map(|(miner, _)| {
+            vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("account", json![miner]),
+                ("amount", json![NearToken::from_millinear(350)]),
+            ]
+        })
+        .collect();
+
+    for validator in [VALIDATOR_1, VALIDATOR_2, VALIDATOR_3] {
+        rewards.push(vec![
+            ("request_id", json![DEFAULT_REQUEST_ID]),
+            ("account", json![validator]),
+            ("amount", json![NearToken::from_millinear(500)]),
+        ]);
+    }
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "top_miners".to_string(),
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("ranking_size", json![10]),
+                ("top_miners", json![top_miners]),
+            ],
+        },
+        Log::EventBatch {
+            event_name: "reward_distributed".to_string(),
+            data: rewards,
+        },
+    ]);
 }